@@ -0,0 +1,144 @@
+use crate::build;
+use maze;
+use rand::{seq::SliceRandom, Rng};
+
+///
+/// Data only maze generator
+///
+pub fn generate_maze(monitor: monitor::MazeMonitor) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    build::fill_maze_with_walls(&mut lk.maze);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let mut walk = build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (rng.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
+            col: 2 * (rng.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
+        },
+    );
+    build::build_path(&mut lk.maze, walk);
+    *lk.maze.get_mut(walk.row, walk.col) |= build::BUILDER_BIT;
+    let mut unvisited = unvisited_squares(&lk.maze);
+    while unvisited > 0 {
+        let next = random_step(&lk.maze, walk, &mut rng);
+        if !build::has_builder_bit(&lk.maze, next) {
+            build::join_squares(&mut lk.maze, walk, next);
+            unvisited -= 1;
+        }
+        walk = next;
+    }
+}
+
+///
+/// History based generator for animation and playback.
+///
+pub fn generate_history(monitor: monitor::MazeMonitor) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("builder could not take lock"),
+    };
+    build::fill_maze_history_with_walls(&mut lk.maze);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let mut walk = build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (rng.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
+            col: 2 * (rng.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
+        },
+    );
+    build::build_path_history(&mut lk.maze, walk);
+    *lk.maze.get_mut(walk.row, walk.col) |= build::BUILDER_BIT;
+    let mut unvisited = unvisited_squares(&lk.maze);
+    while unvisited > 0 {
+        let next = random_step(&lk.maze, walk, &mut rng);
+        if build::has_builder_bit(&lk.maze, next) {
+            walk_history(&mut lk.maze, walk, next);
+        } else {
+            carve_history(&mut lk.maze, walk, next);
+            unvisited -= 1;
+        }
+        walk = next;
+    }
+    // The walker leaves no trace on the finished maze so the solvers start from clean squares.
+    let square = lk.maze.get(walk.row, walk.col);
+    lk.maze.build_history.push(maze::Delta {
+        id: walk,
+        before: square,
+        after: square & !build::MARKERS_MASK,
+        burst: 1,
+    });
+    *lk.maze.get_mut(walk.row, walk.col) &= !build::MARKERS_MASK;
+}
+
+// The walker only ever stands on one square so its arrow moves in the same frame as the step.
+fn walk_history(maze: &mut maze::Maze, walk: maze::Point, next: maze::Point) {
+    let walk_before = maze.get(walk.row, walk.col);
+    let next_before = maze.get(next.row, next.col);
+    *maze.get_mut(walk.row, walk.col) &= !build::MARKERS_MASK;
+    *maze.get_mut(next.row, next.col) |= build::origin_marker(walk, next);
+    maze.build_history.push_burst(&[
+        maze::Delta {
+            id: walk,
+            before: walk_before,
+            after: maze.get(walk.row, walk.col),
+            burst: 2,
+        },
+        maze::Delta {
+            id: next,
+            before: next_before,
+            after: maze.get(next.row, next.col),
+            burst: 2,
+        },
+    ]);
+}
+
+// New squares are carved with the arrow already on them and then the old arrow is cleared.
+fn carve_history(maze: &mut maze::Maze, walk: maze::Point, next: maze::Point) {
+    let wall = maze::Point {
+        row: (walk.row + next.row) / 2,
+        col: (walk.col + next.col) / 2,
+    };
+    build::carve_wall_history(maze, wall, 0);
+    build::carve_wall_history(maze, next, build::origin_marker(walk, next));
+    let square = maze.get(walk.row, walk.col);
+    maze.build_history.push(maze::Delta {
+        id: walk,
+        before: square,
+        after: square & !build::MARKERS_MASK,
+        burst: 1,
+    });
+    *maze.get_mut(walk.row, walk.col) &= !build::MARKERS_MASK;
+}
+
+///
+/// Data only helpers for all.
+///
+fn unvisited_squares(maze: &maze::Maze) -> i32 {
+    if !maze.has_mask() {
+        return ((maze.rows() - 1) / 2) * ((maze.cols() - 1) / 2) - 1;
+    }
+    let in_mask = (1..maze.rows() - 1)
+        .step_by(2)
+        .flat_map(|r| (1..maze.cols() - 1).step_by(2).map(move |c| (r, c)))
+        .filter(|&(r, c)| maze.in_mask(r, c))
+        .count();
+    in_mask as i32 - 1
+}
+
+fn random_step(maze: &maze::Maze, walk: maze::Point, rng: &mut impl Rng) -> maze::Point {
+    loop {
+        let dir = build::GENERATE_DIRECTIONS
+            .choose(rng)
+            .expect("cannot choose from empty directions");
+        let next = maze::Point {
+            row: walk.row + dir.row,
+            col: walk.col + dir.col,
+        };
+        if build::is_square_within_perimeter_walls(maze, next) {
+            return next;
+        }
+    }
+}
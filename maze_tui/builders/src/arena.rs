@@ -11,7 +11,9 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
     build::fill_maze_with_walls(&mut lk.maze);
     for r in 1..lk.maze.rows() - 1 {
         for c in 1..lk.maze.cols() - 1 {
-            build::build_path(&mut lk.maze, maze::Point { row: r, col: c });
+            if lk.maze.in_mask(r, c) {
+                build::build_path(&mut lk.maze, maze::Point { row: r, col: c });
+            }
         }
     }
 }
@@ -26,7 +28,9 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
     build::fill_maze_history_with_walls(&mut lk.maze);
     for r in 1..lk.maze.rows() - 1 {
         for c in 1..lk.maze.cols() - 1 {
-            build::build_path_history(&mut lk.maze, maze::Point { row: r, col: c });
+            if lk.maze.in_mask(r, c) {
+                build::build_path_history(&mut lk.maze, maze::Point { row: r, col: c });
+            }
         }
     }
 }
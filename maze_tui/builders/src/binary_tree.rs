@@ -0,0 +1,154 @@
+use crate::build;
+use maze;
+use rand::{seq::SliceRandom, Rng};
+
+// Every square joins one of the two neighbors the bias points toward, so the bias names the
+// corner where the two open corridors along the perimeter meet.
+struct Bias {
+    vertical: maze::Point,
+    horizontal: maze::Point,
+}
+
+const NORTH: maze::Point = maze::Point { row: -2, col: 0 };
+const SOUTH: maze::Point = maze::Point { row: 2, col: 0 };
+const EAST: maze::Point = maze::Point { row: 0, col: 2 };
+const WEST: maze::Point = maze::Point { row: 0, col: -2 };
+
+const NORTHWEST: Bias = Bias {
+    vertical: NORTH,
+    horizontal: WEST,
+};
+const NORTHEAST: Bias = Bias {
+    vertical: NORTH,
+    horizontal: EAST,
+};
+const SOUTHWEST: Bias = Bias {
+    vertical: SOUTH,
+    horizontal: WEST,
+};
+const SOUTHEAST: Bias = Bias {
+    vertical: SOUTH,
+    horizontal: EAST,
+};
+
+// The corner the maze leans toward, northwest unless another is asked for.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Params {
+    bias: Corner,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+enum Corner {
+    #[default]
+    NorthWest,
+    NorthEast,
+    SouthWest,
+    SouthEast,
+}
+
+// Accepts bias=nw, bias=ne, bias=sw, or bias=se.
+impl std::str::FromStr for Params {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in params::param_pairs(s) {
+            match pair? {
+                ("bias", "nw") => params.bias = Corner::NorthWest,
+                ("bias", "ne") => params.bias = Corner::NorthEast,
+                ("bias", "sw") => params.bias = Corner::SouthWest,
+                ("bias", "se") => params.bias = Corner::SouthEast,
+                ("bias", bias) => return Err(format!("bias[{}] must be nw, ne, sw, or se", bias)),
+                (key, _) => return Err(format!("param[{}] must be bias", key)),
+            }
+        }
+        Ok(params)
+    }
+}
+
+impl Params {
+    fn bias(&self) -> Bias {
+        match self.bias {
+            Corner::NorthWest => NORTHWEST,
+            Corner::NorthEast => NORTHEAST,
+            Corner::SouthWest => SOUTHWEST,
+            Corner::SouthEast => SOUTHEAST,
+        }
+    }
+}
+
+///
+/// Data only maze generators
+///
+pub fn generate_maze(monitor: monitor::MazeMonitor, params: &Params) {
+    let bias = params.bias();
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    build::fill_maze_with_walls(&mut lk.maze);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    for r in (1..lk.maze.rows() - 1).step_by(2) {
+        for c in (1..lk.maze.cols() - 1).step_by(2) {
+            if !lk.maze.in_mask(r, c) {
+                continue;
+            }
+            let cur = maze::Point { row: r, col: c };
+            match choose_neighbor(&lk.maze, cur, &bias, &mut rng) {
+                Some(next) => build::join_squares(&mut lk.maze, cur, next),
+                None => build::build_path(&mut lk.maze, cur),
+            }
+        }
+    }
+    build::join_mask_regions(&mut lk.maze, &mut rng);
+}
+
+///
+/// History based generators for animation and playback.
+///
+pub fn generate_history(monitor: monitor::MazeMonitor, params: &Params) {
+    let bias = params.bias();
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("builder could not take lock"),
+    };
+    build::fill_maze_history_with_walls(&mut lk.maze);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    for r in (1..lk.maze.rows() - 1).step_by(2) {
+        for c in (1..lk.maze.cols() - 1).step_by(2) {
+            if !lk.maze.in_mask(r, c) {
+                continue;
+            }
+            let cur = maze::Point { row: r, col: c };
+            match choose_neighbor(&lk.maze, cur, &bias, &mut rng) {
+                Some(next) => build::join_squares_history(&mut lk.maze, cur, next),
+                None => {
+                    build::build_path_history(&mut lk.maze, cur);
+                }
+            }
+        }
+    }
+    build::join_mask_regions_history(&mut lk.maze, &mut rng);
+}
+
+///
+/// Data only helpers for all.
+///
+// Only the corner the bias points toward has no neighbor to join and it is reached by the others.
+// A shaped maze has a corner like this on every edge of its outline so its pieces are joined after.
+fn choose_neighbor(
+    maze: &maze::Maze,
+    cur: maze::Point,
+    bias: &Bias,
+    rng: &mut impl Rng,
+) -> Option<maze::Point> {
+    let candidates: Vec<maze::Point> = [bias.vertical, bias.horizontal]
+        .iter()
+        .map(|dir| maze::Point {
+            row: cur.row + dir.row,
+            col: cur.col + dir.col,
+        })
+        .filter(|&next| build::is_square_within_perimeter_walls(maze, next))
+        .collect();
+    candidates.choose(rng).copied()
+}
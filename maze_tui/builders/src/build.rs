@@ -1,6 +1,8 @@
+use crate::disjoint;
 use maze;
 use print;
 use print::maze_panic;
+use rand::{seq::SliceRandom, Rng};
 use ratatui::{
     buffer::Cell,
     style::{Color as RatColor, Modifier},
@@ -85,8 +87,9 @@ pub const GENERATE_DIRECTIONS: [maze::Point; 4] = [
     maze::Point { row: 0, col: -2 },
 ];
 
-// Control the speed steps of animation in microseconds here.
-pub const BUILDER_SPEEDS: [SpeedUnit; 8] = [0, 5000, 2500, 1000, 500, 250, 100, 1];
+// Control the speed steps of animation in microseconds here. The renderer cannot tick faster than
+// one millisecond so every step stays at or above that to remain distinct.
+pub const BUILDER_SPEEDS: [SpeedUnit; 8] = [0, 20000, 10000, 5000, 3000, 2000, 1500, 1000];
 
 // MAZE BOUNDS CHECKING AND HELPERS-------------------------------------------------------
 
@@ -146,7 +149,27 @@ pub fn is_built(square: maze::Square) -> bool {
 
 #[inline]
 pub fn is_square_within_perimeter_walls(maze: &maze::Maze, next: maze::Point) -> bool {
-    next.row < maze.rows() - 1 && next.row > 0 && next.col < maze.cols() - 1 && next.col > 0
+    next.row < maze.rows() - 1
+        && next.row > 0
+        && next.col < maze.cols() - 1
+        && next.col > 0
+        && maze.in_mask(next.row, next.col)
+}
+
+// Builders pick random starts over the whole rectangle. A shaped maze moves the start to the
+// closest square of the same parity that is in the mask.
+pub fn nearest_in_mask(maze: &maze::Maze, p: maze::Point) -> Option<maze::Point> {
+    if is_square_within_perimeter_walls(maze, p) {
+        return Some(p);
+    }
+    maze.find_nearest(p, 2, |q| is_square_within_perimeter_walls(maze, q))
+}
+
+pub fn start_in_mask(maze: &maze::Maze, p: maze::Point) -> maze::Point {
+    match nearest_in_mask(maze, p) {
+        Some(start) => start,
+        None => maze_panic!("no square in the mask to start building from {:?}", p),
+    }
 }
 
 #[inline]
@@ -169,10 +192,21 @@ fn get_mark_color(square: maze::Square) -> RatColor {
 
 // Data Driven functions no IO or animation concerns.
 
+// A shaped maze is outlined along the edge of its mask instead of the perimeter.
 pub fn build_wall_outline(maze: &mut maze::Maze) {
     for r in 0..maze.rows() {
         for c in 0..maze.cols() {
-            if c == 0 || c == maze.cols() - 1 || r == 0 || r == maze.rows() - 1 {
+            if maze.outside_mask(r, c) {
+                build_path(maze, maze::Point { row: r, col: c });
+                *maze.get_mut(r, c) |= BUILDER_BIT;
+                continue;
+            }
+            if c == 0
+                || c == maze.cols() - 1
+                || r == 0
+                || r == maze.rows() - 1
+                || !maze.in_mask(r, c)
+            {
                 *maze.get_mut(r, c) |= BUILDER_BIT;
                 build_wall_carefully(maze, maze::Point { row: r, col: c });
                 continue;
@@ -183,6 +217,9 @@ pub fn build_wall_outline(maze: &mut maze::Maze) {
 }
 
 pub fn build_wall_line(maze: &mut maze::Maze, p: maze::Point) {
+    if !maze.in_mask(p.row, p.col) {
+        return;
+    }
     let mut wall: maze::WallLine = 0b0;
     if p.row > 0 && maze.wall_at(p.row - 1, p.col) {
         wall |= maze::NORTH_WALL;
@@ -232,6 +269,50 @@ pub fn fill_maze_with_walls(maze: &mut maze::Maze) {
             build_wall(maze, maze::Point { row: r, col: c });
         }
     }
+    cut_out_mask(maze);
+}
+
+// Squares outside the mask become open floor that is already built so no builder enters them.
+pub fn cut_out_mask(maze: &mut maze::Maze) {
+    if !maze.has_mask() {
+        return;
+    }
+    for r in 0..maze.rows() {
+        for c in 0..maze.cols() {
+            if maze.outside_mask(r, c) {
+                build_path(maze, maze::Point { row: r, col: c });
+                *maze.get_mut(r, c) |= BUILDER_BIT;
+            }
+        }
+    }
+}
+
+// Builders that sweep the grid in a fixed pattern assume a rectangle so a mask can cut their
+// maze into pieces. One random wall between each pair of pieces joins them back into one maze.
+pub fn join_mask_regions(maze: &mut maze::Maze, rng: &mut impl Rng) {
+    if !maze.has_mask() {
+        return;
+    }
+    let (mut sets, walls) = mask_regions(maze, rng);
+    for w in walls {
+        let (a, b) = wall_cells(w);
+        if sets.made_union(cell_id(maze, a), cell_id(maze, b)) {
+            join_squares(maze, a, b);
+        }
+    }
+}
+
+// The FROM_* marker pointing back from next to the neighboring square it was reached from.
+pub fn origin_marker(walk: maze::Point, next: maze::Point) -> BacktrackMarker {
+    if next.row > walk.row {
+        FROM_NORTH
+    } else if next.row < walk.row {
+        FROM_SOUTH
+    } else if next.col < walk.col {
+        FROM_EAST
+    } else {
+        FROM_WEST
+    }
 }
 
 pub fn mark_origin(maze: &mut maze::Maze, walk: maze::Point, next: maze::Point) {
@@ -360,11 +441,40 @@ pub fn fill_maze_history_with_walls(maze: &mut maze::Maze) {
             build_wall_history(maze, maze::Point { row: r, col: c });
         }
     }
-    let burst = (maze.rows() * maze.cols()) as usize;
+    let burst = (maze.rows() * maze.cols()) as usize + cut_out_mask_history(maze);
     maze.build_history[0].burst = burst;
     maze.build_history[burst - 1].burst = burst;
 }
 
+pub fn cut_out_mask_history(maze: &mut maze::Maze) -> usize {
+    let mut deltas = 0;
+    if !maze.has_mask() {
+        return deltas;
+    }
+    for r in 0..maze.rows() {
+        for c in 0..maze.cols() {
+            if maze.outside_mask(r, c) {
+                deltas += build_path_history(maze, maze::Point { row: r, col: c });
+                *maze.get_mut(r, c) |= BUILDER_BIT;
+            }
+        }
+    }
+    deltas
+}
+
+pub fn join_mask_regions_history(maze: &mut maze::Maze, rng: &mut impl Rng) {
+    if !maze.has_mask() {
+        return;
+    }
+    let (mut sets, walls) = mask_regions(maze, rng);
+    for w in walls {
+        let (a, b) = wall_cells(w);
+        if sets.made_union(cell_id(maze, a), cell_id(maze, b)) {
+            join_squares_history(maze, a, b);
+        }
+    }
+}
+
 pub fn mark_origin_history(maze: &mut maze::Maze, walk: maze::Point, next: maze::Point) {
     let mut wall = walk;
     let next_before = maze.get(next.row, next.col);
@@ -413,7 +523,17 @@ pub fn build_wall_outline_history(maze: &mut maze::Maze) {
     let mut deltas = 0;
     for r in 0..maze.rows() {
         for c in 0..maze.cols() {
-            if c == 0 || c == maze.cols() - 1 || r == 0 || r == maze.rows() - 1 {
+            if maze.outside_mask(r, c) {
+                deltas += build_path_history(maze, maze::Point { row: r, col: c });
+                *maze.get_mut(r, c) |= BUILDER_BIT;
+                continue;
+            }
+            if c == 0
+                || c == maze.cols() - 1
+                || r == 0
+                || r == maze.rows() - 1
+                || !maze.in_mask(r, c)
+            {
                 *maze.get_mut(r, c) |= BUILDER_BIT;
                 deltas += build_wall_history_carefully(maze, maze::Point { row: r, col: c });
                 continue;
@@ -426,6 +546,9 @@ pub fn build_wall_outline_history(maze: &mut maze::Maze) {
 }
 
 pub fn build_wall_line_history(maze: &mut maze::Maze, p: maze::Point) {
+    if !maze.in_mask(p.row, p.col) {
+        return;
+    }
     let mut wall_changes = [maze::Delta::default(); 5];
     let mut burst = 1;
     let mut wall: maze::WallLine = 0b0;
@@ -703,14 +826,15 @@ pub fn build_wall_history_carefully(maze: &mut maze::Maze, p: maze::Point) -> us
         *maze.get_mut(p.row, p.col + 1) |= maze::WEST_WALL;
     }
     let before = maze.get(p.row, p.col);
+    let after = (before & !maze::PATH_BIT) | wall;
     deltas[0] = maze::Delta {
         id: p,
         before,
-        after: before | wall,
+        after,
         burst,
     };
     deltas[burst - 1].burst = burst;
-    *maze.get_mut(p.row, p.col) |= (before & !maze::PATH_BIT) | wall;
+    *maze.get_mut(p.row, p.col) = after;
     maze.build_history.push_burst(&deltas[0..burst]);
     burst
 }
@@ -787,6 +911,65 @@ pub fn build_path_history(maze: &mut maze::Maze, p: maze::Point) -> usize {
     burst
 }
 
+///
+/// Data only helpers for joining the pieces of a shaped maze.
+///
+// Cells already joined by an open wall start in the same set and the walls between any two cells
+// in the mask are returned shuffled as candidates for joining the rest.
+fn mask_regions(
+    maze: &maze::Maze,
+    rng: &mut impl Rng,
+) -> (disjoint::DisjointSet, Vec<maze::Point>) {
+    let mut sets = disjoint::DisjointSet::new((maze.rows() / 2 * (maze.cols() / 2)) as usize);
+    let mut walls = Vec::new();
+    for r in 1..maze.rows() - 1 {
+        for c in 1..maze.cols() - 1 {
+            let w = maze::Point { row: r, col: c };
+            if (r + c) % 2 == 0 || !is_square_within_perimeter_walls(maze, w) {
+                continue;
+            }
+            if maze.path_at(r, c) {
+                let (a, b) = wall_cells(w);
+                sets.made_union(cell_id(maze, a), cell_id(maze, b));
+            } else {
+                walls.push(w);
+            }
+        }
+    }
+    walls.shuffle(rng);
+    (sets, walls)
+}
+
+fn wall_cells(w: maze::Point) -> (maze::Point, maze::Point) {
+    if w.row % 2 == 0 {
+        (
+            maze::Point {
+                row: w.row - 1,
+                col: w.col,
+            },
+            maze::Point {
+                row: w.row + 1,
+                col: w.col,
+            },
+        )
+    } else {
+        (
+            maze::Point {
+                row: w.row,
+                col: w.col - 1,
+            },
+            maze::Point {
+                row: w.row,
+                col: w.col + 1,
+            },
+        )
+    }
+}
+
+fn cell_id(maze: &maze::Maze, p: maze::Point) -> usize {
+    (p.row / 2 * (maze.cols() / 2) + p.col / 2) as usize
+}
+
 ///
 /// Display Code: This section is concerned with the lower level details of how elements are
 /// displayed to the screen. For the printers that means interacting with Crossterm's print
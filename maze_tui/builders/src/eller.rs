@@ -4,14 +4,43 @@ use print;
 
 use rand::{
     distributions::{Bernoulli, Distribution},
-    thread_rng, Rng,
+    Rng,
 };
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 const WINDOW_SIZE: usize = 2;
 const DROP_DIST: i32 = 2;
 const NEIGHBOR_DIST: i32 = 2;
 
+// The chance that a square merges with its right neighbor when they are in different sets. Low
+// values leave few merges in a row so more of the maze hangs from vertical drops.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Params {
+    h: f64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { h: 0.66 }
+    }
+}
+
+// Accepts h=probability such as h=0.3.
+impl std::str::FromStr for Params {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in params::param_pairs(s) {
+            match pair? {
+                ("h", h) => params.h = params::parse_probability("h", h)?,
+                (key, _) => return Err(format!("param[{}] must be h", key)),
+            }
+        }
+        Ok(params)
+    }
+}
+
 type SetId = usize;
 
 struct SlidingSetWindow {
@@ -29,16 +58,16 @@ struct IdMergeRequest {
 ///
 /// Data only maze generator
 ///
-pub fn generate_maze(monitor: monitor::MazeMonitor) {
+pub fn generate_maze(monitor: monitor::MazeMonitor, params: &Params) {
     let mut lk = match monitor.lock() {
         Ok(l) => l,
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::fill_maze_with_walls(&mut lk.maze);
-    let mut rng = thread_rng();
-    let coin = Bernoulli::new(0.66);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let coin = Bernoulli::new(params.h);
     let mut window = SlidingSetWindow::new(&lk.maze);
-    let mut sets_in_this_row: HashMap<SetId, Vec<maze::Point>> = HashMap::new();
+    let mut sets_in_this_row: BTreeMap<SetId, Vec<maze::Point>> = BTreeMap::new();
     for r in (1..lk.maze.rows() - 2).step_by(2) {
         window.generate_sets(window.next_row_i());
         for c in (1..lk.maze.cols() - 1).step_by(2) {
@@ -47,7 +76,8 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
                 row: r,
                 col: c + NEIGHBOR_DIST,
             };
-            if !build::is_square_within_perimeter_walls(&lk.maze, next)
+            if !lk.maze.in_mask(r, c)
+                || !build::is_square_within_perimeter_walls(&lk.maze, next)
                 || cur_id == window.get(window.cur_row, next.col as usize)
                 || !coin.expect("Bernoulli coin flip broke").sample(&mut rng)
             {
@@ -65,6 +95,9 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
         }
 
         for c in (1..lk.maze.cols() - 1).step_by(2) {
+            if !lk.maze.in_mask(r, c) {
+                continue;
+            }
             sets_in_this_row
                 .entry(window.get(window.cur_row, c as usize))
                 .or_default()
@@ -93,6 +126,7 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
         sets_in_this_row.clear();
     }
     complete_final_row(&mut lk.maze, &mut window);
+    build::join_mask_regions(&mut lk.maze, &mut rng);
 }
 
 fn merge_cur_row_sets(window: &mut SlidingSetWindow, request: IdMergeRequest) {
@@ -114,7 +148,7 @@ fn complete_final_row(maze: &mut maze::Maze, window: &mut SlidingSetWindow) {
             col: c + NEIGHBOR_DIST,
         };
         let neighbor_id = window.get(set_r, (c + NEIGHBOR_DIST) as usize);
-        if this_id == neighbor_id {
+        if this_id == neighbor_id || !maze.in_mask(r, c) || !maze.in_mask(next.row, next.col) {
             continue;
         }
         build::join_squares(maze, maze::Point { row: r, col: c }, next);
@@ -129,16 +163,16 @@ fn complete_final_row(maze: &mut maze::Maze, window: &mut SlidingSetWindow) {
 ///
 /// History based generator for animation and playback.
 ///
-pub fn generate_history(monitor: monitor::MazeMonitor) {
+pub fn generate_history(monitor: monitor::MazeMonitor, params: &Params) {
     let mut lk = match monitor.lock() {
         Ok(l) => l,
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::fill_maze_history_with_walls(&mut lk.maze);
-    let mut rng = thread_rng();
-    let coin = Bernoulli::new(0.66);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let coin = Bernoulli::new(params.h);
     let mut window = SlidingSetWindow::new(&lk.maze);
-    let mut sets_in_this_row: HashMap<SetId, Vec<maze::Point>> = HashMap::new();
+    let mut sets_in_this_row: BTreeMap<SetId, Vec<maze::Point>> = BTreeMap::new();
     for r in (1..lk.maze.rows() - 2).step_by(2) {
         window.generate_sets(window.next_row_i());
         for c in (1..lk.maze.cols() - 1).step_by(2) {
@@ -147,7 +181,8 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
                 row: r,
                 col: c + NEIGHBOR_DIST,
             };
-            if !build::is_square_within_perimeter_walls(&lk.maze, next)
+            if !lk.maze.in_mask(r, c)
+                || !build::is_square_within_perimeter_walls(&lk.maze, next)
                 || cur_id == window.get(window.cur_row, next.col as usize)
                 || !coin.expect("Bernoulli coin flip broke").sample(&mut rng)
             {
@@ -165,6 +200,9 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
         }
 
         for c in (1..lk.maze.cols() - 1).step_by(2) {
+            if !lk.maze.in_mask(r, c) {
+                continue;
+            }
             sets_in_this_row
                 .entry(window.get(window.cur_row, c as usize))
                 .or_default()
@@ -193,6 +231,7 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
         sets_in_this_row.clear();
     }
     complete_final_row_history(&mut lk.maze, &mut window);
+    build::join_mask_regions_history(&mut lk.maze, &mut rng);
 }
 
 fn complete_final_row_history(maze: &mut maze::Maze, window: &mut SlidingSetWindow) {
@@ -205,7 +244,7 @@ fn complete_final_row_history(maze: &mut maze::Maze, window: &mut SlidingSetWind
             col: c + NEIGHBOR_DIST,
         };
         let neighbor_id = window.get(set_r, (c + NEIGHBOR_DIST) as usize);
-        if this_id == neighbor_id {
+        if this_id == neighbor_id || !maze.in_mask(r, c) || !maze.in_mask(next.row, next.col) {
             continue;
         }
         build::join_squares_history(maze, maze::Point { row: r, col: c }, next);
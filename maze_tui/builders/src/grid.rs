@@ -1,7 +1,7 @@
 use crate::build;
 use maze;
 
-use rand::{seq::SliceRandom, thread_rng, Rng};
+use rand::{seq::SliceRandom, Rng};
 
 const RUN_LIMIT: i32 = 4;
 
@@ -19,11 +19,14 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::fill_maze_with_walls(&mut lk.maze);
-    let mut rng = thread_rng();
-    let mut dfs: Vec<maze::Point> = Vec::from([maze::Point {
-        row: 2 * (rng.gen_range(1..lk.maze.rows() - 1) / 2) + 1,
-        col: 2 * (rng.gen_range(1..lk.maze.cols() - 1) / 2) + 1,
-    }]);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let mut dfs: Vec<maze::Point> = Vec::from([build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (rng.gen_range(1..lk.maze.rows() - 1) / 2) + 1,
+            col: 2 * (rng.gen_range(1..lk.maze.cols() - 1) / 2) + 1,
+        },
+    )]);
     let mut random_direction_indices: Vec<usize> = (0..build::NUM_DIRECTIONS).collect();
     while let Some(run) = dfs.last().cloned() {
         random_direction_indices.shuffle(&mut rng);
@@ -71,11 +74,14 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::fill_maze_history_with_walls(&mut lk.maze);
-    let mut rng = thread_rng();
-    let mut dfs: Vec<maze::Point> = Vec::from([maze::Point {
-        row: 2 * (rng.gen_range(1..lk.maze.rows() - 1) / 2) + 1,
-        col: 2 * (rng.gen_range(1..lk.maze.cols() - 1) / 2) + 1,
-    }]);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let mut dfs: Vec<maze::Point> = Vec::from([build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (rng.gen_range(1..lk.maze.rows() - 1) / 2) + 1,
+            col: 2 * (rng.gen_range(1..lk.maze.cols() - 1) / 2) + 1,
+        },
+    )]);
     let mut random_direction_indices: Vec<usize> = (0..build::NUM_DIRECTIONS).collect();
     while let Some(run) = dfs.last().cloned() {
         random_direction_indices.shuffle(&mut rng);
@@ -0,0 +1,202 @@
+use crate::build;
+use maze;
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    seq::SliceRandom,
+    Rng,
+};
+use std::collections::VecDeque;
+
+// The order of the weights in a Policy. Newest alone is a depth first search and random alone
+// is a flavor of Prim's algorithm so mixing the two slides the texture between them.
+const SELECTIONS: [&str; 4] = ["newest", "oldest", "random", "middle"];
+const NEWEST: usize = 0;
+const OLDEST: usize = 1;
+const RANDOM: usize = 2;
+const MIDDLE: usize = 3;
+
+// Weights are relative so newest=3,random=1 and newest=75,random=25 build the same maze.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Policy {
+    weights: [u32; 4],
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            weights: [50, 0, 50, 0],
+        }
+    }
+}
+
+// Accepts weighted selections such as newest=75,random=25. A lone newest=1 picks one selection.
+impl std::str::FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut weights = [0; 4];
+        for pair in params::param_pairs(s) {
+            let (name, weight) = pair?;
+            let weight = match weight.parse::<u32>() {
+                Ok(w) if w <= 100 => w,
+                _ => {
+                    return Err(format!(
+                        "{}[{}] must be a number from 0 to 100",
+                        name, weight
+                    ))
+                }
+            };
+            match SELECTIONS.iter().position(|&s| s == name) {
+                Some(i) => weights[i] += weight,
+                None => {
+                    return Err(format!(
+                        "selection[{}] must be one of {}",
+                        name,
+                        SELECTIONS.join(", ")
+                    ))
+                }
+            }
+        }
+        if weights.iter().all(|&w| w == 0) {
+            return Err(format!("policy[{}] needs at least one nonzero weight", s));
+        }
+        Ok(Self { weights })
+    }
+}
+
+///
+/// Data only maze generator
+///
+pub fn generate_maze(monitor: monitor::MazeMonitor, policy: &Policy) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    build::fill_maze_with_walls(&mut lk.maze);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let selection = WeightedIndex::new(policy.weights)
+        .expect("growing tree policy was validated with a nonzero weight");
+    let start = build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (rng.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
+            col: 2 * (rng.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
+        },
+    );
+    build::build_path(&mut lk.maze, start);
+    *lk.maze.get_mut(start.row, start.col) |= build::BUILDER_BIT;
+    let mut active = VecDeque::from([start]);
+    let mut directions = build::GENERATE_DIRECTIONS;
+    while !active.is_empty() {
+        let i = choose_active(active.len(), selection.sample(&mut rng), &mut rng);
+        let cur = active[i];
+        match unbuilt_neighbor(&lk.maze, cur, &mut directions, &mut rng) {
+            Some(next) => {
+                build::join_squares(&mut lk.maze, cur, next);
+                active.push_back(next);
+            }
+            None => {
+                active.remove(i);
+            }
+        }
+    }
+}
+
+///
+/// History based generator for animation and playback.
+///
+pub fn generate_history(monitor: monitor::MazeMonitor, policy: &Policy) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("builder could not take lock"),
+    };
+    build::fill_maze_history_with_walls(&mut lk.maze);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let selection = WeightedIndex::new(policy.weights)
+        .expect("growing tree policy was validated with a nonzero weight");
+    let start = build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (rng.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
+            col: 2 * (rng.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
+        },
+    );
+    build::carve_wall_history(&mut lk.maze, start, 0);
+    let mut active = VecDeque::from([start]);
+    let mut directions = build::GENERATE_DIRECTIONS;
+    while !active.is_empty() {
+        let i = choose_active(active.len(), selection.sample(&mut rng), &mut rng);
+        let cur = active[i];
+        match unbuilt_neighbor(&lk.maze, cur, &mut directions, &mut rng) {
+            // Squares keep their arrow while they are active so the list is visible as it grows.
+            Some(next) => {
+                let wall = maze::Point {
+                    row: (cur.row + next.row) / 2,
+                    col: (cur.col + next.col) / 2,
+                };
+                build::carve_wall_history(&mut lk.maze, wall, 0);
+                build::carve_wall_history(&mut lk.maze, next, build::origin_marker(cur, next));
+                active.push_back(next);
+            }
+            None => {
+                let square = lk.maze.get(cur.row, cur.col);
+                lk.maze.build_history.push(maze::Delta {
+                    id: cur,
+                    before: square,
+                    after: square & !build::MARKERS_MASK,
+                    burst: 1,
+                });
+                *lk.maze.get_mut(cur.row, cur.col) &= !build::MARKERS_MASK;
+                active.remove(i);
+            }
+        }
+    }
+}
+
+///
+/// Data only helpers for all.
+///
+fn choose_active(len: usize, selection: usize, rng: &mut impl Rng) -> usize {
+    match selection {
+        NEWEST => len - 1,
+        OLDEST => 0,
+        RANDOM => rng.gen_range(0..len),
+        MIDDLE => len / 2,
+        _ => print::maze_panic!("growing tree selection[{}] out of range", selection),
+    }
+}
+
+fn unbuilt_neighbor(
+    maze: &maze::Maze,
+    cur: maze::Point,
+    directions: &mut [maze::Point; build::NUM_DIRECTIONS],
+    rng: &mut impl Rng,
+) -> Option<maze::Point> {
+    directions.shuffle(rng);
+    directions
+        .iter()
+        .map(|dir| maze::Point {
+            row: cur.row + dir.row,
+            col: cur.col + dir.col,
+        })
+        .find(|&next| build::can_build_new_square(maze, next))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_sums_named_weights() {
+        let policy: Policy = "newest=75,random=25,newest=5".parse().unwrap();
+        assert_eq!(policy.weights, [80, 0, 25, 0]);
+    }
+
+    #[test]
+    fn policy_rejects_bad_selections() {
+        assert!("newest".parse::<Policy>().is_err());
+        assert!("newest=101".parse::<Policy>().is_err());
+        assert!("sideways=1".parse::<Policy>().is_err());
+        assert!("oldest=0,middle=0".parse::<Policy>().is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::build;
 use maze;
-use rand::{seq::SliceRandom, thread_rng, Rng};
+use rand::{seq::SliceRandom, Rng};
 
 type DirectionMarker = build::BacktrackMarker;
 
@@ -9,20 +9,60 @@ const GOING_EAST: DirectionMarker = build::FROM_EAST;
 const GOING_SOUTH: DirectionMarker = build::FROM_SOUTH;
 const GOING_WEST: DirectionMarker = build::FROM_WEST;
 
+// The order the hunt checks squares within a row. Rows are always hunted top to bottom so the
+// laser can sweep down the maze, but the first square found in a row decides where the next
+// walk begins.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Params {
+    scan: Scan,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+enum Scan {
+    #[default]
+    Left,
+    Right,
+    Random,
+}
+
+// Accepts scan=left, scan=right, or scan=random.
+impl std::str::FromStr for Params {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in params::param_pairs(s) {
+            match pair? {
+                ("scan", "left") => params.scan = Scan::Left,
+                ("scan", "right") => params.scan = Scan::Right,
+                ("scan", "random") => params.scan = Scan::Random,
+                ("scan", scan) => {
+                    return Err(format!("scan[{}] must be left, right, or random", scan))
+                }
+                (key, _) => return Err(format!("param[{}] must be scan", key)),
+            }
+        }
+        Ok(params)
+    }
+}
+
 ///
 /// Data only maze generator
 ///
-pub fn generate_maze(monitor: monitor::MazeMonitor) {
+pub fn generate_maze(monitor: monitor::MazeMonitor, params: &Params) {
     let mut lk = match monitor.lock() {
         Ok(l) => l,
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::fill_maze_with_walls(&mut lk.maze);
-    let mut gen = thread_rng();
-    let start: maze::Point = maze::Point {
-        row: 2 * (gen.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
-        col: 2 * (gen.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
-    };
+    let mut gen = lk.maze.rng(maze::BUILD_STREAM);
+    let start: maze::Point = build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (gen.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
+            col: 2 * (gen.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
+        },
+    );
     let mut random_direction_indices: [usize; 4] = [0, 1, 2, 3];
     let mut cur: maze::Point = start;
     let mut highest_completed_row = 1;
@@ -42,8 +82,9 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
         }
 
         let mut set_highest_completed_row = false;
+        let columns = scan_columns(params, &lk.maze, &mut gen);
         for r in (highest_completed_row..lk.maze.rows() - 1).step_by(2) {
-            for c in (1..lk.maze.cols() - 1).step_by(2) {
+            for &c in columns.iter() {
                 let start_candidate = maze::Point { row: r, col: c };
                 if !build::is_built(lk.maze.get(r, c)) {
                     if !set_highest_completed_row {
@@ -73,17 +114,20 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
 ///
 /// History based generator for animation and playback.
 ///
-pub fn generate_history(monitor: monitor::MazeMonitor) {
+pub fn generate_history(monitor: monitor::MazeMonitor, params: &Params) {
     let mut lk = match monitor.lock() {
         Ok(l) => l,
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::fill_maze_history_with_walls(&mut lk.maze);
-    let mut gen = thread_rng();
-    let start: maze::Point = maze::Point {
-        row: 2 * (gen.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
-        col: 2 * (gen.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
-    };
+    let mut gen = lk.maze.rng(maze::BUILD_STREAM);
+    let start: maze::Point = build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (gen.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
+            col: 2 * (gen.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
+        },
+    );
     let mut random_direction_indices: [usize; 4] = [0, 1, 2, 3];
     let mut cur: maze::Point = start;
     let mut highest_completed_row = 1;
@@ -103,8 +147,9 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
             }
         }
         let mut set_highest_completed_row = false;
+        let columns = scan_columns(params, &lk.maze, &mut gen);
         for r in (highest_completed_row..lk.maze.rows() - 1).step_by(2) {
-            for c in (1..lk.maze.cols() - 1).step_by(2) {
+            for &c in columns.iter() {
                 let start_candidate = maze::Point { row: r, col: c };
                 if !build::is_built(lk.maze.get(r, c)) {
                     if !set_highest_completed_row {
@@ -141,6 +186,20 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
     }
 }
 
+///
+/// Data only helpers.
+///
+// Left to right never draws from the generator so the default hunt stays the same.
+fn scan_columns(params: &Params, maze: &maze::Maze, rng: &mut impl Rng) -> Vec<i32> {
+    let mut columns: Vec<i32> = (1..maze.cols() - 1).step_by(2).collect();
+    match params.scan {
+        Scan::Left => {}
+        Scan::Right => columns.reverse(),
+        Scan::Random => columns.shuffle(rng),
+    }
+    columns
+}
+
 fn carve_forward_history(maze: &mut maze::Maze, cur: maze::Point, next: maze::Point, min_row: i32) {
     let mut wall: maze::Point = cur;
     let direction = if next.row < cur.row {
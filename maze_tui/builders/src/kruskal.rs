@@ -121,7 +121,8 @@ fn load_shuffled_walls(maze: &maze::Maze) -> Vec<maze::Point> {
             walls.push(maze::Point { row: r, col: c });
         }
     }
-    walls.shuffle(&mut thread_rng());
+    walls.retain(|&w| build::is_square_within_perimeter_walls(maze, w));
+    walls.shuffle(&mut maze.rng(maze::BUILD_STREAM));
     walls
 }
 
@@ -1,13 +1,19 @@
+pub mod aldous_broder;
 pub mod arena;
+pub mod binary_tree;
 pub mod build;
 pub(crate) mod disjoint;
 pub mod eller;
 pub mod grid;
+pub mod growing_tree;
 pub mod hunt_kill;
 pub mod kruskal;
+pub mod mask;
 pub mod modify;
 pub mod prim;
 pub mod recursive_backtracker;
 pub mod recursive_subdivision;
+pub mod sidewinder;
+pub mod validate;
 pub mod wilson_adder;
 pub mod wilson_carver;
@@ -0,0 +1,311 @@
+use maze;
+use std::cmp;
+use std::collections::VecDeque;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Every shape stretches to fill the maze so the rows and cols of the maze set its proportions.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shape {
+    Circle,
+    Heart,
+    // Rows of cells from ASCII art or rendered text where true keeps the cell in the maze.
+    Bitmap(Vec<Vec<bool>>),
+}
+
+// Solvers need room for a start and several finishes so smaller shapes are refused.
+pub const MIN_MASK_CELLS: usize = 8;
+
+const GLYPH_WIDTH: usize = 5;
+const GLYPH_HEIGHT: usize = 7;
+// Each row of a glyph reads left to right from the highest of its five bits.
+#[rustfmt::skip]
+const FONT: [(char, [u8; GLYPH_HEIGHT]); 41] = [
+    ('A', [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    ('B', [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110]),
+    ('C', [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110]),
+    ('D', [0b11110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b11110]),
+    ('E', [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111]),
+    ('F', [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000]),
+    ('G', [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111]),
+    ('H', [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    ('I', [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+    ('J', [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100]),
+    ('K', [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001]),
+    ('L', [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111]),
+    ('M', [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001]),
+    ('N', [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001]),
+    ('O', [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+    ('P', [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000]),
+    ('Q', [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101]),
+    ('R', [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001]),
+    ('S', [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110]),
+    ('T', [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100]),
+    ('U', [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+    ('V', [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100]),
+    ('W', [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010]),
+    ('X', [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001]),
+    ('Y', [0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100, 0b00100]),
+    ('Z', [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111]),
+    ('0', [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110]),
+    ('1', [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+    ('2', [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111]),
+    ('3', [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110]),
+    ('4', [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010]),
+    ('5', [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110]),
+    ('6', [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110]),
+    ('7', [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000]),
+    ('8', [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110]),
+    ('9', [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100]),
+    (' ', [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000]),
+    ('!', [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100]),
+    ('?', [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100]),
+    ('.', [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100]),
+    ('-', [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000]),
+];
+
+impl Shape {
+    // Letters are drawn in a small block font with a one cell margin so they do not touch the edge.
+    pub fn text(words: &str) -> Result<Shape, String> {
+        if words.is_empty() {
+            return Err(String::from("text[] needs at least one character"));
+        }
+        let mut glyphs = Vec::new();
+        for ch in words.chars() {
+            match FONT.iter().find(|(f, _)| *f == ch.to_ascii_uppercase()) {
+                Some((_, rows)) => glyphs.push(rows),
+                None => {
+                    return Err(format!(
+                        "text[{}] may only use letters, digits, spaces, and ! ? . -",
+                        words
+                    ))
+                }
+            }
+        }
+        let width = glyphs.len() * (GLYPH_WIDTH + 1) + 1;
+        let mut bitmap = vec![vec![false; width]; GLYPH_HEIGHT + 2];
+        for (i, rows) in glyphs.iter().enumerate() {
+            for (r, bits) in rows.iter().enumerate() {
+                for c in 0..GLYPH_WIDTH {
+                    bitmap[r + 1][i * (GLYPH_WIDTH + 1) + 1 + c] =
+                        bits & (1 << (GLYPH_WIDTH - 1 - c)) != 0;
+                }
+            }
+        }
+        Ok(Shape::Bitmap(bitmap))
+    }
+
+    // Spaces and dots in the art are cut out of the maze and any other character is kept.
+    pub fn load(path: &Path) -> io::Result<Shape> {
+        let art = fs::read_to_string(path)?;
+        let lines: Vec<&str> = art.lines().collect();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let bitmap: Vec<Vec<bool>> = lines
+            .iter()
+            .map(|l| {
+                let mut row: Vec<bool> = l.chars().map(|c| c != ' ' && c != '.').collect();
+                row.resize(width, false);
+                row
+            })
+            .collect();
+        if !bitmap.iter().flatten().any(|&keep| keep) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the art has no characters to keep",
+            ));
+        }
+        Ok(Shape::Bitmap(bitmap))
+    }
+}
+
+///
+/// Masks are applied once to a new maze before any builder runs.
+///
+pub fn apply(maze: &mut maze::Maze, shape: &Shape) -> Result<(), String> {
+    let rows = (maze.rows() / 2) as usize;
+    let cols = (maze.cols() / 2) as usize;
+    let mut cells = vec![false; rows * cols];
+    for r in 0..rows {
+        for c in 0..cols {
+            cells[r * cols + c] = keeps_cell(shape, r, c, rows, cols);
+        }
+    }
+    let kept = cells.iter().filter(|&&keep| keep).count();
+    if kept < MIN_MASK_CELLS {
+        return Err(format!(
+            "the mask keeps {} squares but a maze of this size needs at least {}",
+            kept, MIN_MASK_CELLS
+        ));
+    }
+    connect_cells(&mut cells, rows, cols);
+    maze.maze.mask = (0..maze.rows())
+        .flat_map(|r| (0..maze.cols()).map(move |c| (r, c)))
+        .map(|(r, c)| is_inside(&cells, cols, maze.rows(), maze.cols(), r, c))
+        .collect();
+    Ok(())
+}
+
+///
+/// Data only helpers.
+///
+fn keeps_cell(shape: &Shape, r: usize, c: usize, rows: usize, cols: usize) -> bool {
+    // Cell centers map onto -1 to 1 across the maze with y growing upward.
+    let x = (2 * c + 1) as f64 / cols as f64 - 1.0;
+    let y = 1.0 - (2 * r + 1) as f64 / rows as f64;
+    match shape {
+        Shape::Circle => x * x + y * y <= 1.0,
+        Shape::Heart => {
+            // The heart curve spans about 1.14 either side of center and 1 below to 1.24 above.
+            let hx = x * 1.2;
+            let hy = y * 1.18 + 0.12;
+            let a = hx * hx + hy * hy - 1.0;
+            a * a * a - hx * hx * hy * hy * hy <= 0.0
+        }
+        // Shrinking art keeps a cell if anything it covers is kept so thin strokes are not lost.
+        Shape::Bitmap(bitmap) => {
+            let (top, bottom) = covered(r, rows, bitmap.len());
+            let (left, right) = covered(c, cols, bitmap[0].len());
+            bitmap[top..bottom]
+                .iter()
+                .any(|row| row[left..right].iter().any(|&keep| keep))
+        }
+    }
+}
+
+fn covered(cell: usize, cells: usize, len: usize) -> (usize, usize) {
+    let start = cell * len / cells;
+    (start, cmp::max(start + 1, (cell + 1) * len / cells))
+}
+
+// Letters and art can leave islands so each one is bridged to the rest along a shortest path.
+fn connect_cells(cells: &mut [bool], rows: usize, cols: usize) {
+    loop {
+        let labels = label_regions(cells, rows, cols);
+        let first = match labels.iter().position(|l| l.is_some()) {
+            Some(i) => i,
+            None => return,
+        };
+        let home = labels[first];
+        let mut parent: Vec<Option<usize>> = vec![None; cells.len()];
+        let mut bfs: VecDeque<usize> = VecDeque::new();
+        for (i, l) in labels.iter().enumerate() {
+            if *l == home {
+                parent[i] = Some(i);
+                bfs.push_back(i);
+            }
+        }
+        let mut island = None;
+        while let Some(cur) = bfs.pop_front() {
+            if labels[cur].is_some() && labels[cur] != home {
+                island = Some(cur);
+                break;
+            }
+            for next in neighbors(cur, rows, cols) {
+                if parent[next].is_none() {
+                    parent[next] = Some(cur);
+                    bfs.push_back(next);
+                }
+            }
+        }
+        let mut cur = match island {
+            Some(i) => i,
+            None => return,
+        };
+        while labels[cur] != home {
+            cells[cur] = true;
+            cur = parent[cur].expect("bridge path must lead back to the first region");
+        }
+    }
+}
+
+fn label_regions(cells: &[bool], rows: usize, cols: usize) -> Vec<Option<usize>> {
+    let mut labels = vec![None; cells.len()];
+    let mut label = 0;
+    for start in 0..cells.len() {
+        if !cells[start] || labels[start].is_some() {
+            continue;
+        }
+        labels[start] = Some(label);
+        let mut bfs = VecDeque::from([start]);
+        while let Some(cur) = bfs.pop_front() {
+            for next in neighbors(cur, rows, cols) {
+                if cells[next] && labels[next].is_none() {
+                    labels[next] = Some(label);
+                    bfs.push_back(next);
+                }
+            }
+        }
+        label += 1;
+    }
+    labels
+}
+
+fn neighbors(i: usize, rows: usize, cols: usize) -> impl Iterator<Item = usize> {
+    let (r, c) = (i / cols, i % cols);
+    [
+        (r > 0).then(|| i - cols),
+        (c + 1 < cols).then_some(i + 1),
+        (r + 1 < rows).then_some(i + cols),
+        (c > 0).then(|| i - 1),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+// A square is inside when every cell it touches is kept so walls between kept and cut cells stay.
+fn is_inside(cells: &[bool], cols: usize, maze_rows: i32, maze_cols: i32, r: i32, c: i32) -> bool {
+    if r == 0 || c == 0 || r == maze_rows - 1 || c == maze_cols - 1 {
+        return false;
+    }
+    let rows = if r % 2 == 1 { [r, r] } else { [r - 1, r + 1] };
+    let cs = if c % 2 == 1 { [c, c] } else { [c - 1, c + 1] };
+    rows.iter().all(|&cell_r| {
+        cs.iter()
+            .all(|&cell_c| cells[(cell_r / 2) as usize * cols + (cell_c / 2) as usize])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{recursive_backtracker, validate};
+
+    fn new_maze(rows: i32, cols: i32) -> maze::Maze {
+        maze::Maze::new(maze::MazeArgs {
+            odd_rows: rows,
+            odd_cols: cols,
+            ..maze::MazeArgs::default()
+        })
+    }
+
+    #[test]
+    fn text_rejects_characters_outside_the_font() {
+        assert!(Shape::text("HI!").is_ok());
+        assert!(Shape::text("a@b").is_err());
+        assert!(Shape::text("").is_err());
+    }
+
+    #[test]
+    fn small_masks_are_refused() {
+        let mut maze = new_maze(5, 5);
+        assert!(apply(&mut maze, &Shape::Circle).is_err());
+    }
+
+    #[test]
+    fn builders_connect_every_masked_square() {
+        for shape in [Shape::Circle, Shape::Heart, Shape::text("A B").unwrap()] {
+            let mut maze = new_maze(31, 61);
+            apply(&mut maze, &shape).unwrap();
+            let monitor = monitor::Monitor::new(maze);
+            recursive_backtracker::generate_maze(monitor.clone());
+            let lk = monitor.lock().unwrap();
+            assert!(lk.maze.maze.has_mask());
+            assert!(
+                validate::validate(&lk.maze.maze).is_perfect(),
+                "{:?}",
+                shape
+            );
+        }
+    }
+}
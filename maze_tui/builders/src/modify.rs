@@ -1,5 +1,68 @@
 use crate::build;
 use maze;
+use rand::{seq::SliceRandom, Rng};
+
+// The chance that each dead end is opened into a loop. A braid of 1 leaves no dead ends at all.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Braid {
+    chance: f64,
+}
+
+impl Default for Braid {
+    fn default() -> Self {
+        Self { chance: 1.0 }
+    }
+}
+
+// Accepts chance=probability such as chance=0.5.
+impl std::str::FromStr for Braid {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut braid = Braid::default();
+        for pair in params::param_pairs(s) {
+            match pair? {
+                ("chance", chance) => braid.chance = params::parse_probability("chance", chance)?,
+                (key, _) => return Err(format!("param[{}] must be chance", key)),
+            }
+        }
+        Ok(braid)
+    }
+}
+
+// The number of times every dead end is filled back in. Each pass shortens every branch by a square.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Sparse {
+    passes: u32,
+}
+
+// Solvers need room for a start and several finishes so filling stops at this many open cells.
+const MIN_OPEN_CELLS: usize = 8;
+
+impl Default for Sparse {
+    fn default() -> Self {
+        Self { passes: 5 }
+    }
+}
+
+// Accepts passes=count such as passes=10.
+impl std::str::FromStr for Sparse {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sparse = Sparse::default();
+        for pair in params::param_pairs(s) {
+            match pair? {
+                ("passes", passes) => match passes.parse::<u32>() {
+                    Ok(p) if p >= 1 => sparse.passes = p,
+                    _ => return Err(format!("passes[{}] must be a number >= 1", passes)),
+                },
+                (key, _) => return Err(format!("param[{}] must be passes", key)),
+            }
+        }
+        Ok(sparse)
+    }
+}
 
 ///
 /// Data only maze generator
@@ -14,9 +77,9 @@ pub fn add_cross(monitor: monitor::MazeMonitor) {
             if (r == lk.maze.rows() / 2 && c > 1 && c < lk.maze.cols() - 2)
                 || (c == lk.maze.cols() / 2 && r > 1 && r < lk.maze.rows() - 2)
             {
-                build::build_path(&mut lk.maze, maze::Point { row: r, col: c });
+                carve_in_mask(&mut lk.maze, maze::Point { row: r, col: c });
                 if c + 1 < lk.maze.cols() - 2 {
-                    build::build_path(&mut lk.maze, maze::Point { row: r, col: c + 1 });
+                    carve_in_mask(&mut lk.maze, maze::Point { row: r, col: c + 1 });
                 }
             }
         }
@@ -36,6 +99,48 @@ pub fn add_x(monitor: monitor::MazeMonitor) {
     }
 }
 
+pub fn add_braid(monitor: monitor::MazeMonitor, braid: &Braid) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    let mut rng = lk.maze.rng(maze::MODIFY_STREAM);
+    for r in (1..lk.maze.rows() - 1).step_by(2) {
+        for c in (1..lk.maze.cols() - 1).step_by(2) {
+            let cur = maze::Point { row: r, col: c };
+            if let Some(wall) = choose_braid_wall(&lk.maze, cur, braid, &mut rng) {
+                build::build_path(&mut lk.maze, wall);
+            }
+        }
+    }
+}
+
+pub fn add_sparse(monitor: monitor::MazeMonitor, sparse: &Sparse) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    let mut open_cells = count_open_cells(&lk.maze);
+    for _ in 0..sparse.passes {
+        let dead_ends = find_dead_ends(&lk.maze);
+        if dead_ends.is_empty() {
+            return;
+        }
+        for cur in dead_ends {
+            if open_cells <= MIN_OPEN_CELLS {
+                return;
+            }
+            if let Some(passage) = dead_end_passage(&lk.maze, cur) {
+                build::build_wall_carefully(&mut lk.maze, cur);
+                open_cells -= 1;
+                if is_dead_end(&lk.maze, passage) {
+                    build::build_wall_carefully(&mut lk.maze, passage);
+                }
+            }
+        }
+    }
+}
+
 fn add_positive_slope(maze: &mut maze::Maze, p: maze::Point) {
     let row_size = maze.rows() as f32 - 2.0f32;
     let col_size = maze.cols() as f32 - 2.0f32;
@@ -44,9 +149,9 @@ fn add_positive_slope(maze: &mut maze::Maze, p: maze::Point) {
     let b = 2.0f32 - (2.0f32 * slope);
     let on_slope = ((cur_row - b) / slope) as i32;
     if p.col == on_slope && p.col < maze.cols() - 2 && p.col > 1 {
-        build::build_path(maze, p);
+        carve_in_mask(maze, p);
         if p.col + 1 < maze.cols() - 2 {
-            build::build_path(
+            carve_in_mask(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -55,7 +160,7 @@ fn add_positive_slope(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col - 1 > 1 {
-            build::build_path(
+            carve_in_mask(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -64,7 +169,7 @@ fn add_positive_slope(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col + 2 < maze.cols() - 2 {
-            build::build_path(
+            carve_in_mask(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -73,7 +178,7 @@ fn add_positive_slope(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col - 2 > 1 {
-            build::build_path(
+            carve_in_mask(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -92,9 +197,9 @@ fn add_negative_slope(maze: &mut maze::Maze, p: maze::Point) {
     let b = row_size - (2.0f32 * slope);
     let on_line = ((cur_row - b) / slope) as i32;
     if p.col == on_line && p.col > 1 && p.col < maze.cols() - 2 && p.row < maze.rows() - 2 {
-        build::build_path(maze, p);
+        carve_in_mask(maze, p);
         if p.col + 1 < maze.cols() - 2 {
-            build::build_path(
+            carve_in_mask(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -103,7 +208,7 @@ fn add_negative_slope(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col - 1 > 1 {
-            build::build_path(
+            carve_in_mask(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -112,7 +217,7 @@ fn add_negative_slope(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col + 2 < maze.cols() - 2 {
-            build::build_path(
+            carve_in_mask(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -121,7 +226,7 @@ fn add_negative_slope(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col - 2 > 1 {
-            build::build_path(
+            carve_in_mask(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -145,9 +250,9 @@ pub fn add_cross_history(monitor: monitor::MazeMonitor) {
             if (r == lk.maze.rows() / 2 && c > 1 && c < lk.maze.cols() - 2)
                 || (c == lk.maze.cols() / 2 && r > 1 && r < lk.maze.rows() - 2)
             {
-                build::build_path_history(&mut lk.maze, maze::Point { row: r, col: c });
+                carve_in_mask_history(&mut lk.maze, maze::Point { row: r, col: c });
                 if c + 1 < lk.maze.cols() - 2 {
-                    build::build_path_history(&mut lk.maze, maze::Point { row: r, col: c + 1 });
+                    carve_in_mask_history(&mut lk.maze, maze::Point { row: r, col: c + 1 });
                 }
             }
         }
@@ -167,6 +272,48 @@ pub fn add_x_history(monitor: monitor::MazeMonitor) {
     }
 }
 
+pub fn add_braid_history(monitor: monitor::MazeMonitor, braid: &Braid) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    let mut rng = lk.maze.rng(maze::MODIFY_STREAM);
+    for r in (1..lk.maze.rows() - 1).step_by(2) {
+        for c in (1..lk.maze.cols() - 1).step_by(2) {
+            let cur = maze::Point { row: r, col: c };
+            if let Some(wall) = choose_braid_wall(&lk.maze, cur, braid, &mut rng) {
+                build::build_path_history(&mut lk.maze, wall);
+            }
+        }
+    }
+}
+
+pub fn add_sparse_history(monitor: monitor::MazeMonitor, sparse: &Sparse) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    let mut open_cells = count_open_cells(&lk.maze);
+    for _ in 0..sparse.passes {
+        let dead_ends = find_dead_ends(&lk.maze);
+        if dead_ends.is_empty() {
+            return;
+        }
+        for cur in dead_ends {
+            if open_cells <= MIN_OPEN_CELLS {
+                return;
+            }
+            if let Some(passage) = dead_end_passage(&lk.maze, cur) {
+                build::build_wall_history_carefully(&mut lk.maze, cur);
+                open_cells -= 1;
+                if is_dead_end(&lk.maze, passage) {
+                    build::build_wall_history_carefully(&mut lk.maze, passage);
+                }
+            }
+        }
+    }
+}
+
 fn add_positive_slope_history(maze: &mut maze::Maze, p: maze::Point) {
     let row_size = maze.rows() as f32 - 2.0f32;
     let col_size = maze.cols() as f32 - 2.0f32;
@@ -175,9 +322,9 @@ fn add_positive_slope_history(maze: &mut maze::Maze, p: maze::Point) {
     let b = 2.0f32 - (2.0f32 * slope);
     let on_slope = ((cur_row - b) / slope) as i32;
     if p.col == on_slope && p.col < maze.cols() - 2 && p.col > 1 {
-        build::build_path_history(maze, p);
+        carve_in_mask_history(maze, p);
         if p.col + 1 < maze.cols() - 2 {
-            build::build_path_history(
+            carve_in_mask_history(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -186,7 +333,7 @@ fn add_positive_slope_history(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col - 1 > 1 {
-            build::build_path_history(
+            carve_in_mask_history(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -195,7 +342,7 @@ fn add_positive_slope_history(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col + 2 < maze.cols() - 2 {
-            build::build_path_history(
+            carve_in_mask_history(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -204,7 +351,7 @@ fn add_positive_slope_history(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col - 2 > 1 {
-            build::build_path_history(
+            carve_in_mask_history(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -223,9 +370,9 @@ fn add_negative_slope_history(maze: &mut maze::Maze, p: maze::Point) {
     let b = row_size - (2.0f32 * slope);
     let on_line = ((cur_row - b) / slope) as i32;
     if p.col == on_line && p.col > 1 && p.col < maze.cols() - 2 && p.row < maze.rows() - 2 {
-        build::build_path_history(maze, p);
+        carve_in_mask_history(maze, p);
         if p.col + 1 < maze.cols() - 2 {
-            build::build_path_history(
+            carve_in_mask_history(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -234,7 +381,7 @@ fn add_negative_slope_history(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col - 1 > 1 {
-            build::build_path_history(
+            carve_in_mask_history(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -243,7 +390,7 @@ fn add_negative_slope_history(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col + 2 < maze.cols() - 2 {
-            build::build_path_history(
+            carve_in_mask_history(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -252,7 +399,7 @@ fn add_negative_slope_history(maze: &mut maze::Maze, p: maze::Point) {
             );
         }
         if p.col - 2 > 1 {
-            build::build_path_history(
+            carve_in_mask_history(
                 maze,
                 maze::Point {
                     row: p.row,
@@ -262,3 +409,154 @@ fn add_negative_slope_history(maze: &mut maze::Maze, p: maze::Point) {
         }
     }
 }
+
+///
+/// Data only helpers for all.
+///
+// Dead ends found earlier in the scan may already be open so each square is checked as it comes.
+fn choose_braid_wall(
+    maze: &maze::Maze,
+    cur: maze::Point,
+    braid: &Braid,
+    rng: &mut impl Rng,
+) -> Option<maze::Point> {
+    if !is_dead_end(maze, cur) || !rng.gen_bool(braid.chance) {
+        return None;
+    }
+    let closed: Vec<(maze::Point, maze::Point)> = build::GENERATE_DIRECTIONS
+        .iter()
+        .filter_map(|dir| {
+            let next = maze::Point {
+                row: cur.row + dir.row,
+                col: cur.col + dir.col,
+            };
+            let wall = maze::Point {
+                row: cur.row + dir.row / 2,
+                col: cur.col + dir.col / 2,
+            };
+            (build::is_square_within_perimeter_walls(maze, next)
+                && !maze.is_open(wall.row, wall.col)
+                && maze.is_open(next.row, next.col))
+            .then_some((wall, next))
+        })
+        .collect();
+    // Joining two dead ends removes both with one wall so prefer that when it is possible.
+    let dead_ends: Vec<maze::Point> = closed
+        .iter()
+        .filter(|(_, next)| is_dead_end(maze, *next))
+        .map(|(wall, _)| *wall)
+        .collect();
+    match dead_ends.choose(rng) {
+        Some(&wall) => Some(wall),
+        None => closed.choose(rng).map(|(wall, _)| *wall),
+    }
+}
+
+// Crossing corridors stop at the edge of a shaped maze so its outline stays closed.
+fn carve_in_mask(maze: &mut maze::Maze, p: maze::Point) {
+    if maze.in_mask(p.row, p.col) {
+        build::build_path(maze, p);
+    }
+}
+
+fn carve_in_mask_history(maze: &mut maze::Maze, p: maze::Point) {
+    if maze.in_mask(p.row, p.col) {
+        build::build_path_history(maze, p);
+    }
+}
+
+// A pass only fills the dead ends that existed when it started so branches shrink evenly.
+fn find_dead_ends(maze: &maze::Maze) -> Vec<maze::Point> {
+    (1..maze.rows() - 1)
+        .step_by(2)
+        .flat_map(|row| {
+            (1..maze.cols() - 1)
+                .step_by(2)
+                .map(move |col| maze::Point { row, col })
+        })
+        .filter(|&p| is_dead_end(maze, p))
+        .collect()
+}
+
+fn count_open_cells(maze: &maze::Maze) -> usize {
+    (1..maze.rows() - 1)
+        .step_by(2)
+        .flat_map(|row| {
+            (1..maze.cols() - 1)
+                .step_by(2)
+                .map(move |col| maze::Point { row, col })
+        })
+        .filter(|&p| maze.is_open(p.row, p.col))
+        .count()
+}
+
+// Two dead ends may face each other across one passage so the last square standing is kept.
+fn dead_end_passage(maze: &maze::Maze, cur: maze::Point) -> Option<maze::Point> {
+    if !is_dead_end(maze, cur) {
+        return None;
+    }
+    maze::CARDINAL_DIRECTIONS
+        .iter()
+        .map(|dir| maze::Point {
+            row: cur.row + dir.row,
+            col: cur.col + dir.col,
+        })
+        .find(|&next| maze.is_open(next.row, next.col))
+}
+
+fn is_dead_end(maze: &maze::Maze, p: maze::Point) -> bool {
+    maze.is_open(p.row, p.col)
+        && maze::CARDINAL_DIRECTIONS
+            .iter()
+            .filter(|dir| maze.is_open(p.row + dir.row, p.col + dir.col))
+            .count()
+            == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{recursive_backtracker, validate};
+
+    fn perfect_maze(rows: i32, cols: i32) -> monitor::MazeMonitor {
+        let monitor = monitor::Monitor::new(maze::Maze::new(maze::MazeArgs {
+            odd_rows: rows,
+            odd_cols: cols,
+            ..maze::MazeArgs::default()
+        }));
+        recursive_backtracker::generate_maze(monitor.clone());
+        monitor
+    }
+
+    #[test]
+    fn braid_chance_must_be_a_probability() {
+        assert_eq!("chance=0.5".parse::<Braid>(), Ok(Braid { chance: 0.5 }));
+        assert!("chance=2".parse::<Braid>().is_err());
+        assert!("passes=2".parse::<Braid>().is_err());
+    }
+
+    #[test]
+    fn sparse_needs_at_least_one_pass() {
+        assert_eq!("passes=10".parse::<Sparse>(), Ok(Sparse { passes: 10 }));
+        assert!("passes=0".parse::<Sparse>().is_err());
+        assert!("chance=0.5".parse::<Sparse>().is_err());
+    }
+
+    #[test]
+    fn braid_removes_every_dead_end() {
+        let monitor = perfect_maze(15, 31);
+        add_braid(monitor.clone(), &Braid::default());
+        let lk = monitor.lock().unwrap();
+        assert!(find_dead_ends(&lk.maze).is_empty());
+        assert!(validate::validate(&lk.maze.maze).is_connected());
+    }
+
+    #[test]
+    fn sparse_stops_at_min_open_cells() {
+        let monitor = perfect_maze(9, 9);
+        add_sparse(monitor.clone(), &Sparse { passes: 100 });
+        let lk = monitor.lock().unwrap();
+        assert_eq!(count_open_cells(&lk.maze), MIN_OPEN_CELLS);
+        assert!(validate::validate(&lk.maze.maze).is_perfect());
+    }
+}
@@ -3,16 +3,52 @@ use maze;
 
 use rand::{
     distributions::{Distribution, Uniform},
-    thread_rng, Rng,
+    Rng,
 };
 use std::collections::{BinaryHeap, HashMap};
 
+const GAUSSIAN_DRAWS: u32 = 4;
+
 #[derive(Clone, Copy, Eq)]
 struct PriorityPoint {
     priority: u8,
     p: maze::Point,
 }
 
+// How square priorities are drawn. Gaussian priorities bunch up in the middle of the range so
+// more neighbors tie and the heap settles those ties instead of the random draw.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Params {
+    weights: Weights,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+enum Weights {
+    #[default]
+    Uniform,
+    Gaussian,
+}
+
+// Accepts weights=uniform or weights=gaussian.
+impl std::str::FromStr for Params {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in params::param_pairs(s) {
+            match pair? {
+                ("weights", "uniform") => params.weights = Weights::Uniform,
+                ("weights", "gaussian") => params.weights = Weights::Gaussian,
+                ("weights", w) => {
+                    return Err(format!("weights[{}] must be uniform or gaussian", w))
+                }
+                (key, _) => return Err(format!("param[{}] must be weights", key)),
+            }
+        }
+        Ok(params)
+    }
+}
+
 impl PartialEq for PriorityPoint {
     fn eq(&self, other: &Self) -> bool {
         self.priority.eq(&other.priority) && self.p.eq(&other.p)
@@ -34,20 +70,23 @@ impl Ord for PriorityPoint {
 ///
 /// Data only maze generator
 ///
-pub fn generate_maze(monitor: monitor::MazeMonitor) {
+pub fn generate_maze(monitor: monitor::MazeMonitor, params: &Params) {
     let mut lk = match monitor.lock() {
         Ok(l) => l,
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::fill_maze_with_walls(&mut lk.maze);
-    let mut rng = thread_rng();
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
     let weight_range = Uniform::from(1..=100);
     let start = PriorityPoint {
-        priority: weight_range.sample(&mut rng),
-        p: maze::Point {
-            row: 2 * rng.gen_range(1..((lk.maze.rows() - 2) / 2)) + 1,
-            col: 2 * rng.gen_range(1..((lk.maze.cols() - 2) / 2)) + 1,
-        },
+        priority: sample_weight(params.weights, &weight_range, &mut rng),
+        p: build::start_in_mask(
+            &lk.maze,
+            maze::Point {
+                row: 2 * rng.gen_range(1..((lk.maze.rows() - 2) / 2)) + 1,
+                col: 2 * rng.gen_range(1..((lk.maze.cols() - 2) / 2)) + 1,
+            },
+        ),
     };
     let mut lookup_weights: HashMap<maze::Point, u8> = HashMap::from([(start.p, start.priority)]);
     let mut pq = BinaryHeap::from([start]);
@@ -65,9 +104,11 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
             // Weights would have been randomly pre-generated anyway. Generate as we go
             // instead. However, once we choose a weight it must always be the same so
             // we cache that weight and will find it if we choose to join that square later.
-            let weight = *lookup_weights
-                .entry(next)
-                .or_insert(weight_range.sample(&mut rng));
+            let weight = *lookup_weights.entry(next).or_insert(sample_weight(
+                params.weights,
+                &weight_range,
+                &mut rng,
+            ));
             if weight > max_weight {
                 max_weight = weight;
                 max_neighbor.replace(PriorityPoint {
@@ -88,20 +129,23 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
 ///
 /// History based generator for animation and playback.
 ///
-pub fn generate_history(monitor: monitor::MazeMonitor) {
+pub fn generate_history(monitor: monitor::MazeMonitor, params: &Params) {
     let mut lk = match monitor.lock() {
         Ok(l) => l,
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::fill_maze_history_with_walls(&mut lk.maze);
-    let mut rng = thread_rng();
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
     let weight_range = Uniform::from(1..=100);
     let start = PriorityPoint {
-        priority: weight_range.sample(&mut rng),
-        p: maze::Point {
-            row: 2 * rng.gen_range(1..((lk.maze.rows() - 2) / 2)) + 1,
-            col: 2 * rng.gen_range(1..((lk.maze.cols() - 2) / 2)) + 1,
-        },
+        priority: sample_weight(params.weights, &weight_range, &mut rng),
+        p: build::start_in_mask(
+            &lk.maze,
+            maze::Point {
+                row: 2 * rng.gen_range(1..((lk.maze.rows() - 2) / 2)) + 1,
+                col: 2 * rng.gen_range(1..((lk.maze.cols() - 2) / 2)) + 1,
+            },
+        ),
     };
     let mut lookup_weights: HashMap<maze::Point, u8> = HashMap::from([(start.p, start.priority)]);
     let mut pq = BinaryHeap::from([start]);
@@ -119,9 +163,11 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
             // Weights would have been randomly pre-generated anyway. Generate as we go
             // instead. However, once we choose a weight it must always be the same so
             // we cache that weight and will find it if we choose to join that square later.
-            let weight = *lookup_weights
-                .entry(next)
-                .or_insert(weight_range.sample(&mut rng));
+            let weight = *lookup_weights.entry(next).or_insert(sample_weight(
+                params.weights,
+                &weight_range,
+                &mut rng,
+            ));
             if weight > max_weight {
                 max_weight = weight;
                 max_neighbor.replace(PriorityPoint {
@@ -138,3 +184,19 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
         }
     }
 }
+
+///
+/// Data only helpers.
+///
+// Averaging a few uniform draws is a close enough bell curve without another dependency.
+fn sample_weight(weights: Weights, weight_range: &Uniform<u8>, rng: &mut impl Rng) -> u8 {
+    match weights {
+        Weights::Uniform => weight_range.sample(rng),
+        Weights::Gaussian => {
+            let sum: u32 = (0..GAUSSIAN_DRAWS)
+                .map(|_| weight_range.sample(rng) as u32)
+                .sum();
+            (sum / GAUSSIAN_DRAWS) as u8
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::build;
 use maze;
-use rand::{seq::SliceRandom, thread_rng, Rng};
+use rand::{seq::SliceRandom, Rng};
 
 ///
 /// Data only maze generator
@@ -11,11 +11,14 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::fill_maze_with_walls(&mut lk.maze);
-    let mut gen = thread_rng();
-    let start: maze::Point = maze::Point {
-        row: 2 * (gen.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
-        col: 2 * (gen.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
-    };
+    let mut gen = lk.maze.rng(maze::BUILD_STREAM);
+    let start: maze::Point = build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (gen.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
+            col: 2 * (gen.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
+        },
+    );
     let mut random_direction_indices: [usize; build::NUM_DIRECTIONS] = [0, 1, 2, 3];
     let mut cur: maze::Point = start;
     'descending: loop {
@@ -32,6 +35,10 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
                 continue 'descending;
             }
         }
+        // The start of a shaped maze may still have branches to explore when the search returns.
+        if cur == start {
+            return;
+        }
         let dir: build::BacktrackMarker = lk.maze.get(cur.row, cur.col) & build::MARKERS_MASK;
         // The solvers will need these bits later so we need to clear bits.
         let half: &maze::Point = &build::BACKTRACKING_HALF_POINTS[dir as usize];
@@ -44,10 +51,6 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
         *lk.maze.get_mut(half_step.row, half_step.col) &= !build::MARKERS_MASK;
         cur.row += backtracking.row;
         cur.col += backtracking.col;
-
-        if cur == start {
-            return;
-        }
     }
 }
 
@@ -60,11 +63,14 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
         Err(_) => print::maze_panic!("builder could not take lock"),
     };
     build::fill_maze_history_with_walls(&mut lk.maze);
-    let mut gen = thread_rng();
-    let start: maze::Point = maze::Point {
-        row: 2 * (gen.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
-        col: 2 * (gen.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
-    };
+    let mut gen = lk.maze.rng(maze::BUILD_STREAM);
+    let start: maze::Point = build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (gen.gen_range(1..lk.maze.rows() - 2) / 2) + 1,
+            col: 2 * (gen.gen_range(1..lk.maze.cols() - 2) / 2) + 1,
+        },
+    );
     let mut random_direction_indices: [usize; build::NUM_DIRECTIONS] = [0, 1, 2, 3];
     let mut cur: maze::Point = start;
     'descending: loop {
@@ -81,6 +87,10 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
                 continue 'descending;
             }
         }
+        // The start of a shaped maze may still have branches to explore when the search returns.
+        if cur == start {
+            return;
+        }
         let dir: build::BacktrackMarker = lk.maze.get(cur.row, cur.col) & build::MARKERS_MASK;
         // The solvers will need these bits later so we need to clear bits.
         let half: &maze::Point = &build::BACKTRACKING_HALF_POINTS[dir as usize];
@@ -107,9 +117,5 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
         *lk.maze.get_mut(half_step.row, half_step.col) &= !build::MARKERS_MASK;
         cur.row += backtracking.row;
         cur.col += backtracking.col;
-
-        if cur == start {
-            return;
-        }
     }
 }
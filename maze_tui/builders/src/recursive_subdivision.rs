@@ -1,7 +1,7 @@
 use crate::build;
 use maze;
 
-use rand::{rngs::ThreadRng, thread_rng, Rng};
+use rand::{rngs::StdRng, Rng};
 
 type Height = i32;
 type Width = i32;
@@ -15,24 +15,86 @@ struct Chamber {
 
 const MIN_CHAMBER: i32 = 3;
 
+// Chambers no larger than room squares on either side may stay open instead of being divided.
+// Each one that fits stays open with the given chance. A room of zero divides everything.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Params {
+    room: i32,
+    chance: f64,
+    split: Split,
+}
+
+// Where a chamber is divided. The middle split draws nested boxes rather than a random fractal.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Split {
+    Random,
+    Middle,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            room: 0,
+            chance: 1.0,
+            split: Split::Random,
+        }
+    }
+}
+
+// Accepts any of room=squares, chance=probability, and split=random or middle.
+impl std::str::FromStr for Params {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in params::param_pairs(s) {
+            match pair? {
+                ("room", room) => match room.parse::<i32>() {
+                    Ok(r) if r >= 1 => params.room = r,
+                    _ => return Err(format!("room[{}] must be a number of squares >= 1", room)),
+                },
+                ("chance", chance) => params.chance = params::parse_probability("chance", chance)?,
+                ("split", "random") => params.split = Split::Random,
+                ("split", "middle") => params.split = Split::Middle,
+                ("split", split) => {
+                    return Err(format!("split[{}] must be random or middle", split))
+                }
+                (key, _) => {
+                    return Err(format!(
+                        "param[{}] must be one of room, chance, or split",
+                        key
+                    ))
+                }
+            }
+        }
+        if params.room == 0 && params.chance < 1.0 {
+            return Err(format!("params[{}] need a room size to use a chance", s));
+        }
+        Ok(params)
+    }
+}
+
 ///
 /// Data only maze generator
 ///
-pub fn generate_maze(monitor: monitor::MazeMonitor) {
+pub fn generate_maze(monitor: monitor::MazeMonitor, params: &Params) {
     let mut lk = match monitor.lock() {
         Ok(l) => l,
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::build_wall_outline(&mut lk.maze);
-    let mut rng = thread_rng();
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
     let mut chamber_stack: Vec<Chamber> = Vec::from([Chamber {
         offset: maze::Point { row: 0, col: 0 },
         h: lk.maze.rows(),
         w: lk.maze.cols(),
     }]);
     while let Some(chamber) = chamber_stack.pop() {
+        if is_room(params, chamber, &mut rng) {
+            continue;
+        }
         if chamber.h >= chamber.w && chamber.w > MIN_CHAMBER {
-            let divide = rand_even_div(&mut rng, chamber.h);
+            let divide = even_div(params, &mut rng, chamber.h);
             let passage = rand_odd_pass(&mut rng, chamber.w);
             for c in 0..chamber.w {
                 if c == passage {
@@ -60,7 +122,7 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
                 w: chamber.w,
             });
         } else if chamber.w > chamber.h && chamber.h > MIN_CHAMBER {
-            let divide = rand_even_div(&mut rng, chamber.w);
+            let divide = even_div(params, &mut rng, chamber.w);
             let passage = rand_odd_pass(&mut rng, chamber.h);
             for r in 0..chamber.h {
                 if r == passage {
@@ -89,26 +151,31 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
             });
         }
     }
+    // Passages cut outside a shape leave its pieces walled off from each other.
+    build::join_mask_regions(&mut lk.maze, &mut rng);
 }
 
 ///
 /// History based generator for animation and playback.
 ///
-pub fn generate_history(monitor: monitor::MazeMonitor) {
+pub fn generate_history(monitor: monitor::MazeMonitor, params: &Params) {
     let mut lk = match monitor.lock() {
         Ok(l) => l,
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::build_wall_outline_history(&mut lk.maze);
-    let mut rng = thread_rng();
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
     let mut chamber_stack: Vec<Chamber> = Vec::from([Chamber {
         offset: maze::Point { row: 0, col: 0 },
         h: lk.maze.rows(),
         w: lk.maze.cols(),
     }]);
     while let Some(chamber) = chamber_stack.pop() {
+        if is_room(params, chamber, &mut rng) {
+            continue;
+        }
         if chamber.h >= chamber.w && chamber.w > MIN_CHAMBER {
-            let divide = rand_even_div(&mut rng, chamber.h);
+            let divide = even_div(params, &mut rng, chamber.h);
             let passage = rand_odd_pass(&mut rng, chamber.w);
             for c in 0..chamber.w {
                 if c == passage {
@@ -136,7 +203,7 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
                 w: chamber.w,
             });
         } else if chamber.w > chamber.h && chamber.h > MIN_CHAMBER {
-            let divide = rand_even_div(&mut rng, chamber.w);
+            let divide = even_div(params, &mut rng, chamber.w);
             let passage = rand_odd_pass(&mut rng, chamber.h);
             for r in 0..chamber.h {
                 if r == passage {
@@ -165,15 +232,27 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
             });
         }
     }
+    build::join_mask_regions_history(&mut lk.maze, &mut rng);
 }
 
 ///
 /// Data only helpers.
 ///
-fn rand_even_div(rng: &mut ThreadRng, axis_limit: i32) -> i32 {
-    2 * rng.gen_range(1..=((axis_limit - 2) / 2))
+// Rooms never draw from the generator unless requested so plain fractal mazes stay the same.
+fn is_room(params: &Params, chamber: Chamber, rng: &mut StdRng) -> bool {
+    params.room > 0
+        && (chamber.h - 1) / 2 <= params.room
+        && (chamber.w - 1) / 2 <= params.room
+        && rng.gen_bool(params.chance)
+}
+
+fn even_div(params: &Params, rng: &mut StdRng, axis_limit: i32) -> i32 {
+    match params.split {
+        Split::Random => 2 * rng.gen_range(1..=((axis_limit - 2) / 2)),
+        Split::Middle => 2 * (((axis_limit - 2) / 2 + 1) / 2),
+    }
 }
 
-fn rand_odd_pass(rng: &mut ThreadRng, axis_limit: i32) -> i32 {
+fn rand_odd_pass(rng: &mut StdRng, axis_limit: i32) -> i32 {
     2 * rng.gen_range(1..=((axis_limit - 2) / 2)) + 1
 }
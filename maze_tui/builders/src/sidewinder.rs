@@ -0,0 +1,214 @@
+use crate::build;
+use maze;
+use rand::{
+    distributions::{Bernoulli, Distribution},
+    seq::SliceRandom,
+};
+
+// Runs grow along one axis and every closed run carves a single passage out toward the bias.
+// The line of squares along the bias side has nowhere to go so it becomes one long corridor.
+struct Bias {
+    out: maze::Point,
+    along: maze::Point,
+}
+
+enum RunStep {
+    Extend(maze::Point),
+    Close,
+    EndCorridor,
+}
+
+const CLOSE_RUN_PROBABILITY: f64 = 0.5;
+
+const NORTH: Bias = Bias {
+    out: maze::Point { row: -2, col: 0 },
+    along: maze::Point { row: 0, col: 2 },
+};
+const SOUTH: Bias = Bias {
+    out: maze::Point { row: 2, col: 0 },
+    along: maze::Point { row: 0, col: 2 },
+};
+const EAST: Bias = Bias {
+    out: maze::Point { row: 0, col: 2 },
+    along: maze::Point { row: 2, col: 0 },
+};
+const WEST: Bias = Bias {
+    out: maze::Point { row: 0, col: -2 },
+    along: maze::Point { row: 2, col: 0 },
+};
+
+// The side the long corridor runs along, north unless another is asked for.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Params {
+    bias: Side,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+enum Side {
+    #[default]
+    North,
+    South,
+    East,
+    West,
+}
+
+// Accepts bias=n, bias=s, bias=e, or bias=w.
+impl std::str::FromStr for Params {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in params::param_pairs(s) {
+            match pair? {
+                ("bias", "n") => params.bias = Side::North,
+                ("bias", "s") => params.bias = Side::South,
+                ("bias", "e") => params.bias = Side::East,
+                ("bias", "w") => params.bias = Side::West,
+                ("bias", bias) => return Err(format!("bias[{}] must be n, s, e, or w", bias)),
+                (key, _) => return Err(format!("param[{}] must be bias", key)),
+            }
+        }
+        Ok(params)
+    }
+}
+
+impl Params {
+    fn bias(&self) -> Bias {
+        match self.bias {
+            Side::North => NORTH,
+            Side::South => SOUTH,
+            Side::East => EAST,
+            Side::West => WEST,
+        }
+    }
+}
+
+///
+/// Data only maze generators
+///
+pub fn generate_maze(monitor: monitor::MazeMonitor, params: &Params) {
+    let bias = params.bias();
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    build::fill_maze_with_walls(&mut lk.maze);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let coin = Bernoulli::new(CLOSE_RUN_PROBABILITY).expect("Bernoulli coin flip broke");
+    let mut run: Vec<maze::Point> = Vec::new();
+    for cur in sweep(&lk.maze, &bias) {
+        run.push(cur);
+        match next_join(&lk.maze, &run, &bias, || coin.sample(&mut rng)) {
+            RunStep::Extend(next) => build::join_squares(&mut lk.maze, cur, next),
+            RunStep::Close => {
+                match exits(&lk.maze, &run, &bias).choose(&mut rng) {
+                    Some(&from) => build::join_squares(&mut lk.maze, from, step(from, bias.out)),
+                    None => {
+                        build::build_path(&mut lk.maze, cur);
+                    }
+                }
+                run.clear();
+            }
+            RunStep::EndCorridor => {
+                build::build_path(&mut lk.maze, cur);
+                run.clear();
+            }
+        }
+    }
+    build::join_mask_regions(&mut lk.maze, &mut rng);
+}
+
+///
+/// History based generators for animation and playback.
+///
+pub fn generate_history(monitor: monitor::MazeMonitor, params: &Params) {
+    let bias = params.bias();
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("builder could not take lock"),
+    };
+    build::fill_maze_history_with_walls(&mut lk.maze);
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let coin = Bernoulli::new(CLOSE_RUN_PROBABILITY).expect("Bernoulli coin flip broke");
+    let mut run: Vec<maze::Point> = Vec::new();
+    for cur in sweep(&lk.maze, &bias) {
+        run.push(cur);
+        match next_join(&lk.maze, &run, &bias, || coin.sample(&mut rng)) {
+            RunStep::Extend(next) => build::join_squares_history(&mut lk.maze, cur, next),
+            RunStep::Close => {
+                match exits(&lk.maze, &run, &bias).choose(&mut rng) {
+                    Some(&from) => {
+                        build::join_squares_history(&mut lk.maze, from, step(from, bias.out))
+                    }
+                    None => {
+                        build::build_path_history(&mut lk.maze, cur);
+                    }
+                }
+                run.clear();
+            }
+            RunStep::EndCorridor => {
+                build::build_path_history(&mut lk.maze, cur);
+                run.clear();
+            }
+        }
+    }
+    build::join_mask_regions_history(&mut lk.maze, &mut rng);
+}
+
+///
+/// Data only helpers for all.
+///
+// Lines run perpendicular to the bias so each one is finished before the next one starts.
+fn sweep(maze: &maze::Maze, bias: &Bias) -> Vec<maze::Point> {
+    let rows = (1..maze.rows() - 1).step_by(2);
+    let cols = (1..maze.cols() - 1).step_by(2);
+    let squares: Vec<maze::Point> = if bias.along.col != 0 {
+        rows.flat_map(|row| cols.clone().map(move |col| maze::Point { row, col }))
+            .collect()
+    } else {
+        cols.flat_map(|col| rows.clone().map(move |row| maze::Point { row, col }))
+            .collect()
+    };
+    squares
+        .into_iter()
+        .filter(|p| maze.in_mask(p.row, p.col))
+        .collect()
+}
+
+// Every square in a run of a rectangle can step out but the edge of a shape may block some of them.
+// A run with no way out is left for the pieces of the shape to be joined at the end.
+fn exits(maze: &maze::Maze, run: &[maze::Point], bias: &Bias) -> Vec<maze::Point> {
+    run.iter()
+        .copied()
+        .filter(|&p| build::is_square_within_perimeter_walls(maze, step(p, bias.out)))
+        .collect()
+}
+
+// The coin is only flipped when the run could either grow or close.
+fn next_join(
+    maze: &maze::Maze,
+    run: &[maze::Point],
+    bias: &Bias,
+    mut close: impl FnMut() -> bool,
+) -> RunStep {
+    let cur = *run.last().expect("sidewinder run cannot be empty");
+    let extend = step(cur, bias.along);
+    let can_extend = build::is_square_within_perimeter_walls(maze, extend);
+    if !build::is_square_within_perimeter_walls(maze, step(cur, bias.out)) {
+        if can_extend {
+            return RunStep::Extend(extend);
+        }
+        return RunStep::EndCorridor;
+    }
+    if !can_extend || close() {
+        return RunStep::Close;
+    }
+    RunStep::Extend(extend)
+}
+
+fn step(p: maze::Point, dir: maze::Point) -> maze::Point {
+    maze::Point {
+        row: p.row + dir.row,
+        col: p.col + dir.col,
+    }
+}
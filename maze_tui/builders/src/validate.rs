@@ -0,0 +1,183 @@
+use crate::disjoint;
+use maze;
+
+use std::fmt;
+
+// The most wall mismatches a report keeps. A broken builder tends to break a whole region and the
+// first few squares are enough to find where it went wrong.
+pub const MAX_MISMATCHES: usize = 8;
+
+// What a validation found. Regions and loops are counted over open squares, so the passages a
+// modification cuts between cells count the same as the passages a builder carves.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub open: usize,
+    pub regions: usize,
+    pub loops: usize,
+    pub mismatches: usize,
+    pub first_mismatches: Vec<maze::Point>,
+}
+
+impl Report {
+    #[inline]
+    pub fn is_connected(&self) -> bool {
+        self.regions <= 1
+    }
+
+    // A perfect maze is a spanning tree with exactly one path between any two squares.
+    #[inline]
+    pub fn is_perfect(&self) -> bool {
+        self.is_connected() && self.loops == 0
+    }
+}
+
+///
+/// Data only checks that never change the maze.
+///
+// Every open square joins the set of the squares to its east and south. A join that finds both
+// squares already in one set closes a loop, so the loops are the edges a spanning tree would not
+// need. The wall bits are only checked on walls because solvers reuse those bits on paths.
+pub fn validate(maze: &maze::Blueprint) -> Report {
+    let mut report = Report::default();
+    let mut sets = disjoint::DisjointSet::new(maze.buf.len());
+    for row in 0..maze.rows {
+        for col in 0..maze.cols {
+            let p = maze::Point { row, col };
+            if maze.wall_at(row, col) {
+                if expected_wall_bits(maze, p) != maze.get(row, col) & maze::WALL_MASK {
+                    report.mismatches += 1;
+                    if report.first_mismatches.len() < MAX_MISMATCHES {
+                        report.first_mismatches.push(p);
+                    }
+                }
+                continue;
+            }
+            if !is_open(maze, p) {
+                continue;
+            }
+            report.open += 1;
+            for next in [
+                maze::Point { row, col: col + 1 },
+                maze::Point { row: row + 1, col },
+            ] {
+                if is_open(maze, next)
+                    && !sets.made_union(square_index(maze, p), square_index(maze, next))
+                {
+                    report.loops += 1;
+                }
+            }
+        }
+    }
+    let mut roots: Vec<usize> = (0..maze.buf.len())
+        .filter(|&i| {
+            is_open(
+                maze,
+                maze::Point {
+                    row: i as i32 / maze.cols,
+                    col: i as i32 % maze.cols,
+                },
+            )
+        })
+        .map(|i| sets.find(i))
+        .collect();
+    roots.sort_unstable();
+    roots.dedup();
+    report.regions = roots.len();
+    report
+}
+
+///
+/// Data only helpers.
+///
+// A wall points toward each neighboring wall and away from the edge of the maze.
+fn expected_wall_bits(maze: &maze::Blueprint, p: maze::Point) -> maze::WallLine {
+    let mut wall: maze::WallLine = 0b0;
+    if p.row > 0 && maze.wall_at(p.row - 1, p.col) {
+        wall |= maze::NORTH_WALL;
+    }
+    if p.row + 1 < maze.rows && maze.wall_at(p.row + 1, p.col) {
+        wall |= maze::SOUTH_WALL;
+    }
+    if p.col > 0 && maze.wall_at(p.row, p.col - 1) {
+        wall |= maze::WEST_WALL;
+    }
+    if p.col + 1 < maze.cols && maze.wall_at(p.row, p.col + 1) {
+        wall |= maze::EAST_WALL;
+    }
+    wall
+}
+
+// The perimeter is checked first so the neighbors of an edge square are never read.
+fn is_open(maze: &maze::Blueprint, p: maze::Point) -> bool {
+    p.row > 0
+        && p.row < maze.rows - 1
+        && p.col > 0
+        && p.col < maze.cols - 1
+        && maze.is_open(p.row, p.col)
+}
+
+fn square_index(maze: &maze::Blueprint, p: maze::Point) -> usize {
+    (p.row * maze.cols + p.col) as usize
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let answer = |yes: bool| if yes { "yes" } else { "no" };
+        writeln!(f, "open squares: {}", self.open)?;
+        writeln!(f, "regions: {}", self.regions)?;
+        writeln!(f, "connected: {}", answer(self.is_connected()))?;
+        writeln!(f, "perfect: {}", answer(self.is_perfect()))?;
+        writeln!(f, "loops: {}", self.loops)?;
+        write!(f, "wall mismatches: {}", self.mismatches)?;
+        for p in &self.first_mismatches {
+            write!(f, "\nrow {} col {}", p.row, p.col)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{modify, recursive_backtracker};
+
+    fn built_maze() -> monitor::MazeMonitor {
+        let monitor = monitor::Monitor::new(maze::Maze::new(maze::MazeArgs {
+            odd_rows: 15,
+            odd_cols: 31,
+            ..maze::MazeArgs::default()
+        }));
+        recursive_backtracker::generate_maze(monitor.clone());
+        monitor
+    }
+
+    #[test]
+    fn backtracker_builds_a_perfect_maze() {
+        let monitor = built_maze();
+        let report = validate(&monitor.lock().unwrap().maze.maze);
+        assert!(report.is_perfect());
+        assert_eq!(report.mismatches, 0);
+    }
+
+    #[test]
+    fn braid_leaves_loops_but_one_region() {
+        let monitor = built_maze();
+        modify::add_braid(monitor.clone(), &modify::Braid::default());
+        let report = validate(&monitor.lock().unwrap().maze.maze);
+        assert!(report.is_connected());
+        assert!(report.loops > 0);
+    }
+
+    #[test]
+    fn wrong_wall_bits_are_reported() {
+        let monitor = built_maze();
+        let mut lk = monitor.lock().unwrap();
+        *lk.maze.get_mut(0, 0) ^= maze::WALL_MASK;
+        let report = validate(&lk.maze.maze);
+        assert_eq!(report.mismatches, 1);
+        assert_eq!(
+            report.first_mismatches,
+            vec![maze::Point { row: 0, col: 0 }]
+        );
+    }
+}
@@ -2,7 +2,7 @@ use crate::build;
 use maze;
 use print;
 
-use rand::{seq::SliceRandom, thread_rng, Rng};
+use rand::{seq::SliceRandom, Rng};
 
 const WALK_BIT: maze::Square = 0b0100_0000_0000_0000;
 
@@ -30,14 +30,22 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::build_wall_outline(&mut lk.maze);
-    let mut rng = thread_rng();
-    let mut cur = RandomWalk {
-        prev_row_start: 2,
-        prev: maze::Point { row: 0, col: 0 },
-        walk: maze::Point {
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    // A shaped maze may have no wall squares left inside its outline to grow from.
+    let walk = match build::nearest_in_mask(
+        &lk.maze,
+        maze::Point {
             row: 2 * (rng.gen_range(2..lk.maze.rows() - 1) / 2),
             col: 2 * (rng.gen_range(2..lk.maze.cols() - 1) / 2),
         },
+    ) {
+        Some(p) => p,
+        None => return,
+    };
+    let mut cur = RandomWalk {
+        prev_row_start: 2,
+        prev: maze::Point { row: 0, col: 0 },
+        walk,
         next: maze::Point { row: 0, col: 0 },
     };
     let mut indices: [usize; 4] = [0, 1, 2, 3];
@@ -157,14 +165,22 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::build_wall_outline_history(&mut lk.maze);
-    let mut rng = thread_rng();
-    let mut cur = RandomWalk {
-        prev_row_start: 2,
-        prev: maze::Point { row: 0, col: 0 },
-        walk: maze::Point {
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    // A shaped maze may have no wall squares left inside its outline to grow from.
+    let walk = match build::nearest_in_mask(
+        &lk.maze,
+        maze::Point {
             row: 2 * (rng.gen_range(2..lk.maze.rows() - 1) / 2),
             col: 2 * (rng.gen_range(2..lk.maze.cols() - 1) / 2),
         },
+    ) {
+        Some(p) => p,
+        None => return,
+    };
+    let mut cur = RandomWalk {
+        prev_row_start: 2,
+        prev: maze::Point { row: 0, col: 0 },
+        walk,
         next: maze::Point { row: 0, col: 0 },
     };
     let mut indices: [usize; 4] = [0, 1, 2, 3];
@@ -1,7 +1,7 @@
 use crate::build;
 use maze;
 
-use rand::{seq::SliceRandom, thread_rng, Rng};
+use rand::{seq::SliceRandom, Rng};
 
 const WALK_BIT: maze::Square = 0b0100_0000_0000_0000;
 
@@ -29,17 +29,23 @@ pub fn generate_maze(monitor: monitor::MazeMonitor) {
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::fill_maze_with_walls(&mut lk.maze);
-    let mut rng = thread_rng();
-    let start = maze::Point {
-        row: 2 * (rng.gen_range(2..lk.maze.rows() - 1) / 2) + 1,
-        col: 2 * (rng.gen_range(2..lk.maze.cols() - 1) / 2) + 1,
-    };
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let start = build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (rng.gen_range(2..lk.maze.rows() - 1) / 2) + 1,
+            col: 2 * (rng.gen_range(2..lk.maze.cols() - 1) / 2) + 1,
+        },
+    );
     build::build_path(&mut lk.maze, start);
     *lk.maze.get_mut(start.row, start.col) |= build::BUILDER_BIT;
     let mut cur = RandomWalk {
         prev_row_start: 1,
         prev: maze::Point { row: 0, col: 0 },
-        walk: maze::Point { row: 1, col: 1 },
+        walk: match build::choose_point_from_row_start(&lk.maze, 1, build::ParityPoint::Odd) {
+            Some(p) => p,
+            None => return,
+        },
         next: maze::Point { row: 0, col: 0 },
     };
     *lk.maze.get_mut(cur.walk.row, cur.walk.col) &= !build::MARKERS_MASK;
@@ -160,17 +166,23 @@ pub fn generate_history(monitor: monitor::MazeMonitor) {
         Err(_) => print::maze_panic!("uncontested lock failure"),
     };
     build::fill_maze_history_with_walls(&mut lk.maze);
-    let mut rng = thread_rng();
-    let start = maze::Point {
-        row: 2 * (rng.gen_range(2..lk.maze.rows() - 1) / 2) + 1,
-        col: 2 * (rng.gen_range(2..lk.maze.cols() - 1) / 2) + 1,
-    };
+    let mut rng = lk.maze.rng(maze::BUILD_STREAM);
+    let start = build::start_in_mask(
+        &lk.maze,
+        maze::Point {
+            row: 2 * (rng.gen_range(2..lk.maze.rows() - 1) / 2) + 1,
+            col: 2 * (rng.gen_range(2..lk.maze.cols() - 1) / 2) + 1,
+        },
+    );
     build::build_path_history(&mut lk.maze, start);
     *lk.maze.get_mut(start.row, start.col) |= build::BUILDER_BIT;
     let mut cur = RandomWalk {
         prev_row_start: 1,
         prev: maze::Point { row: 0, col: 0 },
-        walk: maze::Point { row: 1, col: 1 },
+        walk: match build::choose_point_from_row_start(&lk.maze, 1, build::ParityPoint::Odd) {
+            Some(p) => p,
+            None => return,
+        },
         next: maze::Point { row: 0, col: 0 },
     };
     *lk.maze.get_mut(cur.walk.row, cur.walk.col) &= !build::MARKERS_MASK;
@@ -384,12 +396,10 @@ fn break_wall_history(maze: &mut maze::Maze, cur: maze::Point, next: maze::Point
 ///
 /// Data only helpers for all.
 ///
+// Never stepping back speeds up walks in a rectangle but would trap them in the narrow corridors of
+// a shaped maze so those walks may turn around and erase the step they just took.
 fn is_valid_step(maze: &maze::Maze, next: maze::Point, prev: maze::Point) -> bool {
-    next.row > 0
-        && next.row < maze.rows() - 1
-        && next.col > 0
-        && next.col < maze.cols() - 1
-        && next != prev
+    build::is_square_within_perimeter_walls(maze, next) && (next != prev || maze.has_mask())
 }
 
 fn backtrack_point(maze: &maze::Maze, walk: &maze::Point) -> &'static maze::Point {
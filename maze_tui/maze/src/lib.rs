@@ -26,13 +26,19 @@
 // maze start bit--------||| |||| |||| |||| |||| |||| |||| ||||
 // maze goals bit-------|||| |||| |||| |||| |||| |||| |||| ||||
 //                    0b0000 0000 0000 0000 0000 0000 0000 0000
-use rand::seq::SliceRandom;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use std::cmp;
+use std::fs;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Seek, Write};
+use std::mem;
 use std::ops::{Index, IndexMut};
+use std::path::Path;
 
 // Public Types
 
 pub type Square = u32;
 pub type WallLine = u32;
+pub type Seed = u64;
 
 #[derive(Default, Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub struct Point {
@@ -64,6 +70,7 @@ pub struct MazeArgs {
     pub odd_cols: i32,
     pub offset: Offset,
     pub style: MazeStyle,
+    pub seed: Seed,
 }
 
 // This is at the core of our maze. The fundamental information and structure we need.
@@ -74,6 +81,8 @@ pub struct Blueprint {
     pub cols: i32,
     pub offset: Offset,
     pub wall_style_index: usize,
+    // One flag per square for shaped mazes, set where builders may carve. Empty for a rectangle.
+    pub mask: Vec<bool>,
 }
 
 // We will also be tracking how our maze changes for the TUI animation playback.
@@ -90,6 +99,14 @@ pub struct Delta {
 pub struct Tape {
     steps: Vec<Delta>,
     i: usize,
+    keyframes: Vec<Keyframe>,
+}
+
+// A snapshot of the maze buffer just before the frame starting at Delta i plays.
+#[derive(Debug, Default, Clone)]
+struct Keyframe {
+    i: usize,
+    buf: Vec<Square>,
 }
 
 // Our maze now comes with the ability to track the history of algorithms that made and solved it.
@@ -98,6 +115,7 @@ pub struct Maze {
     pub maze: Blueprint,
     pub build_history: Tape,
     pub solve_history: Tape,
+    pub seed: Seed,
 }
 // Read Only Data Available to Any Maze Users
 
@@ -106,6 +124,9 @@ pub const CLEAR_AVAILABLE_BITS: Square = 0x10FFFFFF;
 
 pub const DEFAULT_ROWS: i32 = 31;
 pub const DEFAULT_COLS: i32 = 111;
+// The largest rows or cols any front end accepts. Loaded files are held to it as well so a bad
+// header cannot ask for more memory than a maze typed on the command line ever could.
+pub const MAX_DIMENSION: i32 = 4001;
 pub const PATH_BIT: Square = 0x20000000;
 pub const WALL_MASK: WallLine = 0xF000000;
 pub const WALL_SHIFT: usize = 24;
@@ -114,6 +135,44 @@ pub const NORTH_WALL: WallLine = 0x1000000;
 pub const EAST_WALL: WallLine = 0x2000000;
 pub const SOUTH_WALL: WallLine = 0x4000000;
 pub const WEST_WALL: WallLine = 0x8000000;
+// Every phase of a run draws from its own stream of the maze seed so a builder that consumes more
+// randomness never changes the points a solver picks. Solver thread i uses SOLVE_STREAM + 1 + i.
+pub const BUILD_STREAM: u64 = 0;
+pub const MODIFY_STREAM: u64 = 1;
+pub const SOLVE_STREAM: u64 = 2;
+// A saved Blueprint is plain text so it can be read and diffed. Every file looks like this.
+//
+// maze-tui blueprint 1
+// rows 33
+// cols 111
+// style 1
+// squares
+// 00000000 20000000 ...
+//
+// The version follows the magic words. The style is the MazeStyle index and the squares are
+// rows lines of cols hex Squares each. The offset is a property of the terminal so it is not saved.
+// A shaped maze ends with a mask line and then rows lines of cols characters, # for the squares
+// in the mask and . for the rest, so a loaded maze still keeps its solvers inside the shape.
+pub const BLUEPRINT_MAGIC: &str = "maze-tui blueprint";
+pub const BLUEPRINT_VERSION: u32 = 1;
+// A recording is a whole Maze with its Tapes so a run can be shared and played back elsewhere.
+// Tapes are large so after one text line naming the format and version everything is binary.
+// All numbers are little endian.
+//
+// maze-tui recording 2\n
+// seed: u64, rows: i32, cols: i32, style: u32
+// rows * cols starting Squares: u32 each, the buffer before the first build Delta
+// has mask: u8, then rows * cols mask flags of one u8 each when it is 1
+// build Tape length: u64, then each Delta as id row: i32, id col: i32, before: u32, after: u32,
+//     burst: u32
+// solve Tape length: u64, then each Delta in the same layout
+//
+// Version 1 recordings have no mask section and still load as rectangular mazes.
+// Keyframes are at least this many Deltas apart. Large mazes space them by the size of the buffer
+// instead so all the snapshots together never take more memory than the Tape itself.
+pub const KEYFRAME_INTERVAL: usize = 1024;
+pub const RECORDING_MAGIC: &str = "maze-tui recording";
+pub const RECORDING_VERSION: u32 = 2;
 // Walls are constructed in terms of other walls they need to connect to. For example, read
 // 0b0011 as, "this is a wall square that must connect to other walls to the East and North."
 const WALL_ROW: usize = 16;
@@ -175,12 +234,117 @@ impl Maze {
                 cols,
                 offset: args.offset,
                 wall_style_index: args.style as usize,
+                mask: Vec::new(),
             },
             build_history: Tape::default(),
             solve_history: Tape::default(),
+            seed: args.seed,
         }
     }
 
+    // A loaded Blueprint brings its own dimensions and style. It may be larger than the terminal
+    // because the playback viewport pans over any maze that does not fit.
+    pub fn from_blueprint(mut maze: Blueprint, args: MazeArgs) -> Self {
+        maze.offset = args.offset;
+        Self {
+            maze,
+            build_history: Tape::default(),
+            solve_history: Tape::default(),
+            seed: args.seed,
+        }
+    }
+
+    // The maze buffer must be in its starting state, before any build Delta has been applied.
+    pub fn save_recording(&self, path: &Path) -> io::Result<()> {
+        let mut file = BufWriter::new(fs::File::create(path)?);
+        writeln!(file, "{} {}", RECORDING_MAGIC, RECORDING_VERSION)?;
+        file.write_all(&self.seed.to_le_bytes())?;
+        file.write_all(&self.maze.rows.to_le_bytes())?;
+        file.write_all(&self.maze.cols.to_le_bytes())?;
+        file.write_all(&(self.maze.wall_style_index as u32).to_le_bytes())?;
+        for square in self.maze.buf.iter() {
+            file.write_all(&square.to_le_bytes())?;
+        }
+        file.write_all(&[self.maze.has_mask() as u8])?;
+        for &m in self.maze.mask.iter() {
+            file.write_all(&[m as u8])?;
+        }
+        self.build_history.write(&mut file)?;
+        self.solve_history.write(&mut file)?;
+        file.flush()
+    }
+
+    pub fn load_recording(path: &Path) -> io::Result<Maze> {
+        let mut file = BufReader::new(fs::File::open(path)?);
+        let mut magic = String::new();
+        file.read_line(&mut magic)?;
+        let version = match magic.trim().strip_prefix(RECORDING_MAGIC) {
+            Some(v) => match v.trim().parse::<u32>() {
+                Ok(v) if (1..=RECORDING_VERSION).contains(&v) => v,
+                _ => return Err(bad_file("not a supported maze-tui recording")),
+            },
+            None => return Err(bad_file("not a supported maze-tui recording")),
+        };
+        let seed = u64::from_le_bytes(read_bytes(&mut file)?);
+        let rows = i32::from_le_bytes(read_bytes(&mut file)?);
+        let cols = i32::from_le_bytes(read_bytes(&mut file)?);
+        let wall_style_index = u32::from_le_bytes(read_bytes(&mut file)?) as usize;
+        let squares = square_count(
+            usize::try_from(rows).unwrap_or(0),
+            usize::try_from(cols).unwrap_or(0),
+        )?;
+        if wall_style_index >= ALL_WALL_STYLES.len() {
+            return Err(bad_file("unknown wall style"));
+        }
+        let remaining = file
+            .get_ref()
+            .metadata()?
+            .len()
+            .saturating_sub(file.stream_position()?);
+        if (remaining as usize) / mem::size_of::<Square>() < squares {
+            return Err(bad_file("file is too short for rows and cols"));
+        }
+        let mut buf = Vec::with_capacity(squares);
+        for _ in 0..squares {
+            buf.push(Square::from_le_bytes(read_bytes(&mut file)?));
+        }
+        let mut mask = Vec::new();
+        if version > 1 {
+            match read_bytes::<1>(&mut file)? {
+                [0] => {}
+                [1] => {
+                    mask = vec![0u8; squares];
+                    file.read_exact(&mut mask)?;
+                }
+                _ => return Err(bad_file("malformed mask flag")),
+            }
+        }
+        let maze = Blueprint {
+            buf,
+            rows,
+            cols,
+            offset: Offset::default(),
+            wall_style_index,
+            mask: mask.into_iter().map(|m| m != 0).collect(),
+        };
+        let build_history = Tape::read(&mut file, &maze)?;
+        let solve_history = Tape::read(&mut file, &maze)?;
+        Ok(Maze {
+            maze,
+            build_history,
+            solve_history,
+            seed,
+        })
+    }
+
+    // Spread streams far apart so seed n stream 1 is not just seed n + 1 stream 0.
+    pub fn rng(&self, stream: u64) -> StdRng {
+        StdRng::seed_from_u64(
+            self.seed
+                .wrapping_add(stream.wrapping_mul(0x9E37_79B9_7F4A_7C15)),
+        )
+    }
+
     #[inline]
     pub fn rows(&self) -> i32 {
         self.maze.rows
@@ -247,6 +411,58 @@ impl Maze {
     pub fn path_at(&self, row: i32, col: i32) -> bool {
         self.maze.path_at(row, col)
     }
+
+    #[inline]
+    pub fn has_mask(&self) -> bool {
+        self.maze.has_mask()
+    }
+
+    #[inline]
+    pub fn in_mask(&self, row: i32, col: i32) -> bool {
+        self.maze.in_mask(row, col)
+    }
+
+    #[inline]
+    pub fn outside_mask(&self, row: i32, col: i32) -> bool {
+        self.maze.outside_mask(row, col)
+    }
+
+    #[inline]
+    pub fn is_open(&self, row: i32, col: i32) -> bool {
+        self.maze.is_open(row, col)
+    }
+
+    // Searches outward in square rings so the first match is as close to from as possible.
+    // A step of 2 only visits squares that share the parity of from.
+    pub fn find_nearest(
+        &self,
+        from: Point,
+        step: i32,
+        is_match: impl Fn(Point) -> bool,
+    ) -> Option<Point> {
+        let limit = cmp::max(self.rows(), self.cols());
+        for radius in (step..=limit).step_by(step as usize) {
+            for row in (from.row - radius..=from.row + radius).step_by(step as usize) {
+                if row < 0 || row >= self.rows() {
+                    continue;
+                }
+                let edge = row == from.row - radius || row == from.row + radius;
+                for col in (from.col - radius..=from.col + radius).step_by(step as usize) {
+                    if col < 0 || col >= self.cols() {
+                        continue;
+                    }
+                    if !edge && col != from.col - radius && col != from.col + radius {
+                        continue;
+                    }
+                    let p = Point { row, col };
+                    if is_match(p) {
+                        return Some(p);
+                    }
+                }
+            }
+        }
+        None
+    }
 }
 
 impl Blueprint {
@@ -285,6 +501,175 @@ impl Blueprint {
     pub fn is_mini(&self) -> bool {
         self.wall_style_index == (MazeStyle::Mini as usize)
     }
+
+    #[inline]
+    pub fn has_mask(&self) -> bool {
+        !self.mask.is_empty()
+    }
+
+    // Every square of a rectangular maze is in the mask, including the perimeter.
+    #[inline]
+    pub fn in_mask(&self, row: i32, col: i32) -> bool {
+        self.mask.is_empty() || self.mask[(row * self.cols + col) as usize]
+    }
+
+    // The squares cut out of a shaped maze are paths only so they are never drawn as walls. Anything
+    // that walks the maze asks for open squares so it never wanders out of the shape.
+    #[inline]
+    pub fn is_open(&self, row: i32, col: i32) -> bool {
+        self.path_at(row, col) && self.in_mask(row, col)
+    }
+
+    // A square is outside when no cell it touches is in the mask. The squares that touch the mask
+    // without being in it are left as the walls that outline the shape.
+    pub fn outside_mask(&self, row: i32, col: i32) -> bool {
+        if self.mask.is_empty() {
+            return false;
+        }
+        let rows = if row % 2 == 1 {
+            [row, row]
+        } else {
+            [row - 1, row + 1]
+        };
+        let cols = if col % 2 == 1 {
+            [col, col]
+        } else {
+            [col - 1, col + 1]
+        };
+        !rows.iter().any(|&r| {
+            cols.iter().any(|&c| {
+                r > 0 && r < self.rows - 1 && c > 0 && c < self.cols - 1 && self.in_mask(r, c)
+            })
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut file = BufWriter::new(fs::File::create(path)?);
+        writeln!(file, "{} {}", BLUEPRINT_MAGIC, BLUEPRINT_VERSION)?;
+        writeln!(file, "rows {}", self.rows)?;
+        writeln!(file, "cols {}", self.cols)?;
+        writeln!(file, "style {}", self.wall_style_index)?;
+        writeln!(file, "squares")?;
+        for row in self.buf.chunks(self.cols as usize) {
+            let line: Vec<String> = row.iter().map(|square| format!("{:08X}", square)).collect();
+            writeln!(file, "{}", line.join(" "))?;
+        }
+        if self.has_mask() {
+            writeln!(file, "mask")?;
+            for row in self.mask.chunks(self.cols as usize) {
+                let line: String = row.iter().map(|&m| if m { '#' } else { '.' }).collect();
+                writeln!(file, "{}", line)?;
+            }
+        }
+        file.flush()
+    }
+
+    pub fn load(path: &Path) -> io::Result<Blueprint> {
+        let mut lines = io::BufReader::new(fs::File::open(path)?).lines();
+        let version = header_value(&mut lines, BLUEPRINT_MAGIC)?;
+        if version != BLUEPRINT_VERSION as usize {
+            return Err(bad_file(&format!("unsupported version {}", version)));
+        }
+        let rows = header_value(&mut lines, "rows")?;
+        let cols = header_value(&mut lines, "cols")?;
+        let wall_style_index = header_value(&mut lines, "style")?;
+        let squares = square_count(rows, cols)?;
+        if wall_style_index >= ALL_WALL_STYLES.len() {
+            return Err(bad_file("unknown wall style"));
+        }
+        match lines.next() {
+            Some(Ok(l)) if l.trim() == "squares" => {}
+            _ => return Err(bad_file("missing squares section")),
+        }
+        let mut buf = Vec::with_capacity(squares);
+        for (row, line) in lines.by_ref().take(rows).enumerate() {
+            let line = line?;
+            let start = buf.len();
+            for word in line.split_whitespace() {
+                buf.push(
+                    Square::from_str_radix(word, 16)
+                        .map_err(|_| bad_file(&format!("bad square {}", word)))?,
+                );
+            }
+            // Rows that make up for each other would still add up to rows * cols squares.
+            if buf.len() - start != cols {
+                return Err(bad_file(&format!(
+                    "row {} does not have {} squares",
+                    row, cols
+                )));
+            }
+        }
+        if buf.len() != squares {
+            return Err(bad_file("square count does not match rows and cols"));
+        }
+        let mut mask = Vec::new();
+        let rest = match lines.next() {
+            Some(l) => l?,
+            None => String::new(),
+        };
+        if rest.trim() == "mask" {
+            for (row, line) in lines.take(rows).enumerate() {
+                let start = mask.len();
+                mask.extend(line?.trim().chars().map(|c| c == '#'));
+                if mask.len() - start != cols {
+                    return Err(bad_file(&format!(
+                        "mask row {} does not have {} squares",
+                        row, cols
+                    )));
+                }
+            }
+            if mask.len() != squares {
+                return Err(bad_file("mask size does not match rows and cols"));
+            }
+        } else if !rest.trim().is_empty() {
+            return Err(bad_file("expected mask section after squares"));
+        }
+        Ok(Blueprint {
+            buf,
+            rows: rows as i32,
+            cols: cols as i32,
+            offset: Offset::default(),
+            wall_style_index,
+            mask,
+        })
+    }
+}
+
+// Dimensions come from untrusted headers so they are checked before anything is allocated.
+fn square_count(rows: usize, cols: usize) -> io::Result<usize> {
+    let max = MAX_DIMENSION as usize;
+    if rows < 3 || cols < 3 || rows.is_multiple_of(2) || cols.is_multiple_of(2) {
+        return Err(bad_file("rows and cols must be odd and at least 3"));
+    }
+    if rows > max || cols > max {
+        return Err(bad_file(&format!(
+            "rows and cols must be at most {}",
+            MAX_DIMENSION
+        )));
+    }
+    rows.checked_mul(cols)
+        .ok_or_else(|| bad_file("rows and cols are too large"))
+}
+
+fn read_bytes<const N: usize>(file: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    file.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+// Header lines are a key followed by one number. The magic words are the key of the version line.
+fn header_value(lines: &mut io::Lines<io::BufReader<fs::File>>, key: &str) -> io::Result<usize> {
+    let line = match lines.next() {
+        Some(l) => l?,
+        None => return Err(bad_file(&format!("missing {}", key))),
+    };
+    line.strip_prefix(key)
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .ok_or_else(|| bad_file(&format!("expected {} got {}", key, line)))
+}
+
+fn bad_file(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
 }
 
 impl Default for MazeArgs {
@@ -294,6 +679,7 @@ impl Default for MazeArgs {
             odd_cols: DEFAULT_COLS,
             style: MazeStyle::Sharp,
             offset: Offset::default(),
+            seed: 0,
         }
     }
 }
@@ -347,6 +733,10 @@ impl Tape {
         self.steps.len()
     }
 
+    pub fn pos(&self) -> usize {
+        self.i
+    }
+
     pub fn cur_step(&self) -> Option<&[Delta]> {
         if self.steps.is_empty() {
             return None;
@@ -440,6 +830,122 @@ impl Tape {
         self.i = self.peek_next_index();
         self.i != prev
     }
+
+    // Plays the whole Tape over the start state, saving a keyframe every interval along the way.
+    // The returned maze is the state at the end of the Tape which is where the next Tape begins.
+    pub fn set_keyframes(&mut self, start: &Blueprint) -> Blueprint {
+        let interval = cmp::max(KEYFRAME_INTERVAL, start.buf.len());
+        let mut cur = start.clone();
+        self.keyframes.clear();
+        let mut since_keyframe = interval;
+        let mut i = 0;
+        while i < self.steps.len() {
+            if since_keyframe >= interval {
+                self.keyframes.push(Keyframe {
+                    i,
+                    buf: cur.buf.clone(),
+                });
+                since_keyframe = 0;
+            }
+            let end = cmp::min(i + cmp::max(self.steps[i].burst, 1), self.steps.len());
+            for d in &self.steps[i..end] {
+                *cur.get_mut(d.id.row, d.id.col) = d.after;
+            }
+            since_keyframe += end - i;
+            i = end;
+        }
+        cur
+    }
+
+    // Moves to the frame holding Delta index and leaves the maze as it looks just before that frame
+    // plays. At most one keyframe interval of Deltas is replayed no matter how far away index is.
+    pub fn seek(&mut self, index: usize, maze: &mut Blueprint) -> bool {
+        if self.steps.is_empty() || self.keyframes.is_empty() {
+            return false;
+        }
+        let target = cmp::min(index, self.steps.len() - 1);
+        let k = match self.keyframes.binary_search_by_key(&target, |k| k.i) {
+            Ok(k) => k,
+            Err(k) => k - 1,
+        };
+        maze.buf.copy_from_slice(&self.keyframes[k].buf);
+        let mut i = self.keyframes[k].i;
+        loop {
+            let end = cmp::min(i + cmp::max(self.steps[i].burst, 1), self.steps.len());
+            if end > target || end == self.steps.len() {
+                break;
+            }
+            for d in &self.steps[i..end] {
+                *maze.get_mut(d.id.row, d.id.col) = d.after;
+            }
+            i = end;
+        }
+        self.i = i;
+        true
+    }
+
+    pub fn seek_percent(&mut self, percent: f64, maze: &mut Blueprint) -> bool {
+        let index = (self.steps.len() as f64 * percent.clamp(0.0, 100.0) / 100.0) as usize;
+        self.seek(index, maze)
+    }
+
+    fn write(&self, file: &mut impl Write) -> io::Result<()> {
+        file.write_all(&(self.steps.len() as u64).to_le_bytes())?;
+        for d in self.steps.iter() {
+            file.write_all(&d.id.row.to_le_bytes())?;
+            file.write_all(&d.id.col.to_le_bytes())?;
+            file.write_all(&d.before.to_le_bytes())?;
+            file.write_all(&d.after.to_le_bytes())?;
+            file.write_all(&(d.burst as u32).to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    // Stepping trusts the burst at both ends of every frame so a bad file must never get that far.
+    fn read(file: &mut impl Read, maze: &Blueprint) -> io::Result<Tape> {
+        let len = u64::from_le_bytes(read_bytes(file)?) as usize;
+        let mut steps = Vec::new();
+        for _ in 0..len {
+            let d = Delta {
+                id: Point {
+                    row: i32::from_le_bytes(read_bytes(file)?),
+                    col: i32::from_le_bytes(read_bytes(file)?),
+                },
+                before: Square::from_le_bytes(read_bytes(file)?),
+                after: Square::from_le_bytes(read_bytes(file)?),
+                burst: u32::from_le_bytes(read_bytes(file)?) as usize,
+            };
+            if d.id.row < 0 || d.id.row >= maze.rows || d.id.col < 0 || d.id.col >= maze.cols {
+                return Err(bad_file("delta outside of the maze"));
+            }
+            steps.push(d);
+        }
+        // Solvers label bursts loosely so visit every frame playback could land on in either
+        // direction rather than demanding that the bursts line up in neat frames.
+        let mut seen = vec![false; steps.len()];
+        let mut frames = if steps.is_empty() { vec![] } else { vec![0] };
+        while let Some(i) = frames.pop() {
+            if seen[i] {
+                continue;
+            }
+            seen[i] = true;
+            let burst = steps[i].burst;
+            if burst == 0 || i + burst > steps.len() {
+                return Err(bad_file("malformed delta burst"));
+            }
+            if i + burst < steps.len() {
+                frames.push(i + burst);
+            }
+            if i > 0 && steps[i - 1].burst <= i {
+                frames.push(i - steps[i - 1].burst);
+            }
+        }
+        Ok(Tape {
+            steps,
+            i: 0,
+            keyframes: Vec::new(),
+        })
+    }
 }
 
 ///
@@ -483,3 +989,188 @@ const ALL_WALL_STYLES: [MazeStyle; 8] = [
     MazeStyle::Half,
     MazeStyle::Spikes,
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_maze() -> Blueprint {
+        Maze::new(MazeArgs {
+            odd_rows: 5,
+            odd_cols: 7,
+            ..MazeArgs::default()
+        })
+        .maze
+    }
+
+    // Single Deltas and a burst of three mixed together, every one carving a new path square.
+    fn sample_tape(maze: &Blueprint) -> Tape {
+        let mut tape = Tape::default();
+        let mut burst = Vec::new();
+        for (n, col) in (1..maze.cols - 1).enumerate() {
+            let d = Delta {
+                id: Point { row: 1, col },
+                before: 0,
+                after: PATH_BIT,
+                burst: 1,
+            };
+            if n % 2 == 0 {
+                tape.push(d);
+            } else {
+                burst.push(d);
+            }
+        }
+        let len = burst.len();
+        burst.iter_mut().for_each(|d| d.burst = len);
+        tape.push_burst(&burst);
+        tape
+    }
+
+    #[test]
+    fn tape_round_trips_through_write_and_read() {
+        let maze = small_maze();
+        let tape = sample_tape(&maze);
+        let mut bytes = Vec::new();
+        tape.write(&mut bytes).unwrap();
+        let read = Tape::read(&mut bytes.as_slice(), &maze).unwrap();
+        assert_eq!(read.len(), tape.len());
+        for i in 0..tape.len() {
+            assert_eq!(read[i].id, tape[i].id);
+            assert_eq!(read[i].before, tape[i].before);
+            assert_eq!(read[i].after, tape[i].after);
+            assert_eq!(read[i].burst, tape[i].burst);
+        }
+    }
+
+    #[test]
+    fn tape_read_rejects_truncated_file() {
+        let maze = small_maze();
+        let mut bytes = Vec::new();
+        sample_tape(&maze).write(&mut bytes).unwrap();
+        bytes.truncate(bytes.len() - 3);
+        assert!(Tape::read(&mut bytes.as_slice(), &maze).is_err());
+    }
+
+    #[test]
+    fn tape_read_rejects_corrupt_burst() {
+        let maze = small_maze();
+        let mut tape = sample_tape(&maze);
+        // Playback reads the burst at the start of a frame to step forward over it.
+        let frame = tape.len() - tape[tape.len() - 1].burst;
+        tape[frame].burst = tape.len();
+        let mut bytes = Vec::new();
+        tape.write(&mut bytes).unwrap();
+        assert!(Tape::read(&mut bytes.as_slice(), &maze).is_err());
+
+        tape[frame].burst = 0;
+        let mut bytes = Vec::new();
+        tape.write(&mut bytes).unwrap();
+        assert!(Tape::read(&mut bytes.as_slice(), &maze).is_err());
+    }
+
+    #[test]
+    fn tape_read_rejects_delta_outside_maze() {
+        let maze = small_maze();
+        let mut tape = sample_tape(&maze);
+        tape[0].id = Point {
+            row: maze.rows,
+            col: 0,
+        };
+        let mut bytes = Vec::new();
+        tape.write(&mut bytes).unwrap();
+        assert!(Tape::read(&mut bytes.as_slice(), &maze).is_err());
+    }
+
+    #[test]
+    fn recording_rejects_huge_dimensions() {
+        let path = std::env::temp_dir().join(format!("maze-tui-huge-{}.rec", std::process::id()));
+        let mut bytes = format!("{} {}\n", RECORDING_MAGIC, RECORDING_VERSION).into_bytes();
+        bytes.extend(0u64.to_le_bytes());
+        bytes.extend(65535i32.to_le_bytes());
+        bytes.extend(65535i32.to_le_bytes());
+        bytes.extend(1u32.to_le_bytes());
+        fs::write(&path, bytes).unwrap();
+        let loaded = Maze::load_recording(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(loaded.is_err());
+    }
+
+    #[test]
+    fn blueprint_rejects_ragged_rows() {
+        let maze = small_maze();
+        let path =
+            std::env::temp_dir().join(format!("maze-tui-ragged-{}.maze", std::process::id()));
+        maze.save(&path).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        let mut lines: Vec<String> = text.lines().map(String::from).collect();
+        // Move one square from the first row to the second so the total still matches.
+        let first = lines.iter().position(|l| l == "squares").unwrap() + 1;
+        let cut = lines[first].rfind(' ').unwrap();
+        let moved = lines[first].split_off(cut);
+        lines[first + 1].push_str(&moved);
+        fs::write(&path, lines.join("\n") + "\n").unwrap();
+        let loaded = Blueprint::load(&path);
+        fs::remove_file(&path).unwrap();
+        assert!(loaded.is_err());
+    }
+
+    // Enough Deltas for several keyframes. Squares are toggled over and over so an off by one frame
+    // anywhere shows up in the buffer.
+    fn long_tape(maze: &Blueprint) -> Tape {
+        let mut cur = maze.clone();
+        let mut tape = Tape::default();
+        let squares = (maze.rows * maze.cols) as usize;
+        let mut n = 0;
+        while tape.len() < KEYFRAME_INTERVAL * 3 + 7 {
+            let len = n % 4 + 1;
+            let mut frame = Vec::with_capacity(len);
+            for k in 0..len {
+                let i = (n * 7 + k * 13) % squares;
+                let id = Point {
+                    row: i as i32 / maze.cols,
+                    col: i as i32 % maze.cols,
+                };
+                let before = cur.get(id.row, id.col);
+                let after = before ^ PATH_BIT ^ (n as Square & 0xFF);
+                *cur.get_mut(id.row, id.col) = after;
+                frame.push(Delta {
+                    id,
+                    before,
+                    after,
+                    burst: len,
+                });
+            }
+            tape.push_burst(&frame);
+            n += 1;
+        }
+        tape
+    }
+
+    #[test]
+    fn seek_percent_matches_stepping_from_start() {
+        let start = Maze::new(MazeArgs {
+            odd_rows: 21,
+            odd_cols: 41,
+            ..MazeArgs::default()
+        })
+        .maze;
+        let mut tape = long_tape(&start);
+        tape.set_keyframes(&start);
+        for percent in 0..=100 {
+            let mut seeked = start.clone();
+            assert!(tape.seek_percent(percent as f64, &mut seeked));
+
+            let mut stepper = tape.clone();
+            stepper.start();
+            let mut stepped = start.clone();
+            while stepper.pos() < tape.pos() {
+                for d in stepper.cur_step().unwrap() {
+                    *stepped.get_mut(d.id.row, d.id.col) = d.after;
+                }
+                assert!(stepper.set_next());
+            }
+            assert_eq!(stepper.pos(), tape.pos(), "seek did not land on a frame");
+            assert_eq!(seeked.buf, stepped.buf, "seek to {}% differs", percent);
+        }
+    }
+}
@@ -4,7 +4,7 @@ use std::collections::VecDeque;
 
 use std::thread;
 
-use rand::{thread_rng, Rng};
+use rand::Rng;
 
 ///
 /// Data only modifiers
@@ -15,12 +15,7 @@ pub fn paint_distance_from_center(monitor: monitor::MazeMonitor) {
         Err(_) => print::maze_panic!("Lock panic."),
     };
 
-    let row_mid = lk.maze.rows() / 2;
-    let col_mid = lk.maze.cols() / 2;
-    let start = maze::Point {
-        row: row_mid + 1 - (row_mid % 2),
-        col: col_mid + 1 - (col_mid % 2),
-    };
+    let start = rgb::center_start(&lk.maze);
     let mut map = monitor::MaxMap::new(start, 0);
     let mut bfs = VecDeque::from([(start, 0u64)]);
     *lk.maze.get_mut(start.row, start.col) |= rgb::MEASURED;
@@ -47,7 +42,7 @@ pub fn paint_distance_from_center(monitor: monitor::MazeMonitor) {
 }
 
 fn painter(maze: &mut maze::Maze, map: &monitor::MaxMap) {
-    let mut rng = thread_rng();
+    let mut rng = maze.rng(maze::SOLVE_STREAM);
     let rand_color_choice: usize = rng.gen_range(0..3);
     for r in 0..maze.rows() {
         for c in 0..maze.cols() {
@@ -71,12 +66,7 @@ fn painter(maze: &mut maze::Maze, map: &monitor::MaxMap) {
 ///
 pub fn paint_distance_from_center_history(monitor: monitor::MazeMonitor) {
     let start = if let Ok(mut lk) = monitor.lock() {
-        let row_mid = lk.maze.rows() / 2;
-        let col_mid = lk.maze.cols() / 2;
-        let start = maze::Point {
-            row: row_mid + 1 - (row_mid % 2),
-            col: col_mid + 1 - (col_mid % 2),
-        };
+        let start = rgb::center_start(&lk.maze);
         lk.map.distances.insert(start, 0);
         let mut bfs = VecDeque::from([(start, 0u64)]);
         *lk.maze.get_mut(start.row, start.col) |= rgb::MEASURED;
@@ -104,7 +94,10 @@ pub fn paint_distance_from_center_history(monitor: monitor::MazeMonitor) {
         print::maze_panic!("Thread panic.");
     };
 
-    let mut rng = thread_rng();
+    let mut rng = match monitor.lock() {
+        Ok(lk) => lk.maze.rng(maze::SOLVE_STREAM),
+        Err(p) => print::maze_panic!("Thread panicked with lock: {}", p),
+    };
     let rand_color_choice: usize = rng.gen_range(0..3);
     let mut handles = Vec::with_capacity(rgb::NUM_PAINTERS - 1);
     for painter in 1..rgb::NUM_PAINTERS {
@@ -14,7 +14,7 @@ pub const PAINT_MASK: maze::Square = 0b1111_1111_1111_1111_1111_1111;
 pub const MEASURED: maze::Square = 0b0010_0000_0000_0000_0000_0000_0000;
 pub const MEASURED_MASKS: [maze::Square; 4] = [0x1000000, 0x2000000, 0x4000000, 0x8000000];
 pub const NUM_PAINTERS: usize = 4;
-pub const ANIMATION_SPEEDS: [SpeedUnit; 8] = [0, 10000, 5000, 2000, 1000, 500, 250, 50];
+pub const ANIMATION_SPEEDS: [SpeedUnit; 8] = [0, 30000, 15000, 8000, 4000, 2500, 1500, 1000];
 pub const RED_SHIFT: maze::Square = 16;
 pub const GREEN_SHIFT: maze::Square = 8;
 pub const NUM_DIRECTIONS: u16 = 4;
@@ -28,3 +28,17 @@ pub fn has_paint_vals(square: maze::Square) -> bool {
 pub fn is_measured(square: maze::Square) -> bool {
     (square & MEASURED) != 0
 }
+
+// The center of a shaped maze may be cut out or walled off so start from the closest path instead.
+pub fn center_start(maze: &maze::Maze) -> maze::Point {
+    let row_mid = maze.rows() / 2;
+    let col_mid = maze.cols() / 2;
+    let start = maze::Point {
+        row: row_mid + 1 - (row_mid % 2),
+        col: col_mid + 1 - (col_mid % 2),
+    };
+    if maze.is_open(start.row, start.col) {
+        return start;
+    }
+    solvers::solve::find_nearest_square(maze, start)
+}
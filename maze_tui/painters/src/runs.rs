@@ -4,7 +4,7 @@ use maze;
 use std::collections::VecDeque;
 use std::thread;
 
-use rand::{thread_rng, Rng};
+use rand::Rng;
 
 struct RunPoint {
     len: u64,
@@ -20,12 +20,7 @@ pub fn paint_run_lengths(monitor: monitor::MazeMonitor) {
         Ok(l) => l,
         Err(_) => print::maze_panic!("Lock panic."),
     };
-    let row_mid = lk.maze.rows() / 2;
-    let col_mid = lk.maze.cols() / 2;
-    let start = maze::Point {
-        row: row_mid + 1 - (row_mid % 2),
-        col: col_mid + 1 - (col_mid % 2),
-    };
+    let start = rgb::center_start(&lk.maze);
     let mut map = monitor::MaxMap::new(start, 0);
     let mut bfs = VecDeque::from([RunPoint {
         len: 0,
@@ -66,7 +61,7 @@ pub fn paint_run_lengths(monitor: monitor::MazeMonitor) {
 }
 
 fn painter(maze: &mut maze::Maze, map: &monitor::MaxMap) {
-    let mut rng = thread_rng();
+    let mut rng = maze.rng(maze::SOLVE_STREAM);
     let rand_color_choice: usize = rng.gen_range(0..3);
     for r in 0..maze.rows() {
         for c in 0..maze.cols() {
@@ -90,12 +85,7 @@ fn painter(maze: &mut maze::Maze, map: &monitor::MaxMap) {
 ///
 pub fn paint_run_lengths_history(monitor: monitor::MazeMonitor) {
     let start: maze::Point = if let Ok(mut lk) = monitor.lock() {
-        let row_mid = lk.maze.rows() / 2;
-        let col_mid = lk.maze.cols() / 2;
-        let start = maze::Point {
-            row: row_mid + 1 - (row_mid % 2),
-            col: col_mid + 1 - (col_mid % 2),
-        };
+        let start = rgb::center_start(&lk.maze);
         lk.map.distances.insert(start, 0);
         let mut bfs = VecDeque::from([RunPoint {
             len: 0,
@@ -137,7 +127,10 @@ pub fn paint_run_lengths_history(monitor: monitor::MazeMonitor) {
         print::maze_panic!("Thread panic.");
     };
 
-    let mut rng = thread_rng();
+    let mut rng = match monitor.lock() {
+        Ok(lk) => lk.maze.rng(maze::SOLVE_STREAM),
+        Err(p) => print::maze_panic!("Thread panicked with lock: {}", p),
+    };
     let rand_color_choice: usize = rng.gen_range(0..3);
     let mut handles = Vec::with_capacity(rgb::NUM_PAINTERS);
     for painter in 0..rgb::NUM_PAINTERS - 1 {
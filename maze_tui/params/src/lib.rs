@@ -0,0 +1,42 @@
+///
+/// Parameter parsing shared by every algorithm that accepts name:key=value settings.
+///
+// Splits parameters such as room=6,chance=0.5 into key and value pairs.
+pub fn param_pairs(params: &str) -> impl Iterator<Item = Result<(&str, &str), String>> {
+    params.split(',').map(|part| {
+        part.split_once('=')
+            .ok_or(format!("param[{}] must look like key=value", part))
+    })
+}
+
+pub fn parse_probability(key: &str, value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(p) if (0.0..=1.0).contains(&p) => Ok(p),
+        _ => Err(format!("{}[{}] must be a number from 0 to 1", key, value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn param_pairs_split_on_commas_and_equals() {
+        let pairs: Result<Vec<_>, _> = param_pairs("room=6,chance=0.5").collect();
+        assert_eq!(pairs.unwrap(), vec![("room", "6"), ("chance", "0.5")]);
+    }
+
+    #[test]
+    fn param_pairs_reject_a_bare_key() {
+        assert!(param_pairs("room=6,chance").any(|pair| pair.is_err()));
+    }
+
+    #[test]
+    fn parse_probability_keeps_to_zero_through_one() {
+        assert_eq!(parse_probability("h", "0"), Ok(0.0));
+        assert_eq!(parse_probability("h", "1"), Ok(1.0));
+        assert!(parse_probability("h", "1.5").is_err());
+        assert!(parse_probability("h", "-0.1").is_err());
+        assert!(parse_probability("h", "half").is_err());
+    }
+}
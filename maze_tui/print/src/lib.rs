@@ -1,6 +1,10 @@
 use crossterm::{cursor, terminal, ExecutableCommand, QueueableCommand};
 use std::io::{stdout, Write};
 
+// The panic macro reaches crossterm through this crate so callers need not depend on it.
+#[doc(hidden)]
+pub use crossterm;
+
 // The mazes look WAY better if the cursor square disapears while it builds.
 #[derive(Clone)]
 pub struct InvisibleCursor;
@@ -71,7 +75,7 @@ macro_rules! maze_panic {
         {
             use std::fmt::Write;
             use std::io::stdout;
-            use crossterm::{cursor::Show, ExecutableCommand};
+            use $crate::crossterm::{cursor::Show, ExecutableCommand};
             stdout().execute(Show).expect(
                 "Failed to unhide the cursor. Sorry! Restart your terminal."
             );
@@ -0,0 +1,192 @@
+use rand::{thread_rng, Rng};
+use ratatui::{
+    buffer::Cell,
+    style::{Color, Modifier},
+};
+use solvers::solve;
+use std::io::{self, BufWriter, Write};
+use std::{process::ExitCode, sync::Arc, sync::Mutex};
+
+static USAGE: &str = "usage: run_cli [-b builder] [-m modification] [-s solver] [-w walls]\n\
+    [-seed number] [-r rows] [-c cols] [-mask shape] [-load file] [-save file]\n\
+    [-replay file] [-color]\n\
+    The flags and args match the TUI home page. Rows and cols count maze squares.";
+
+// The same runner the TUI uses plus the few settings that only make sense for a printed maze.
+struct CliRun {
+    run: tables::HistoryRunner,
+    color: bool,
+}
+
+///
+/// Headless maze generation. Builders and solvers run their data only variants because there is
+/// no playback so recording every Delta would only waste time and memory.
+///
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let cli = match set_cli_args(&args) {
+        Ok(cli) => cli,
+        Err(msg) => {
+            eprintln!("{}\n{}", msg, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let color = cli.color;
+    let printed = generate(cli.run).and_then(|maze| match print_maze(&maze, color) {
+        // Piping into something like head that stops reading early is not an error.
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => {
+            Err(format!("could not print maze: {}", e))
+        }
+        _ => Ok(()),
+    });
+    match printed {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(msg) => {
+            eprintln!("{}", msg);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(mut run: tables::HistoryRunner) -> Result<maze::Blueprint, String> {
+    // A recording already knows how its run ends so play both Tapes straight through.
+    if let Some(mut recording) = run.replay.take() {
+        let built = recording.build_history.set_keyframes(&recording.maze);
+        return Ok(recording.solve_history.set_keyframes(&built));
+    }
+    let mut maze = match &run.load {
+        Some(blueprint) => maze::Maze::from_blueprint(blueprint.clone(), run.args),
+        None => maze::Maze::new(run.args),
+    };
+    // A loaded maze keeps the mask it was saved with.
+    if let (None, Some(shape)) = (&run.load, &run.mask) {
+        tables::mask::apply(&mut maze, shape)
+            .map_err(|e| format!("could not apply mask: {}", e))?;
+    }
+    let monitor = monitor::Monitor::new(maze);
+    if run.load.is_none() {
+        (run.build.get_data_fn())(monitor.clone(), &run.build_params);
+    }
+    if let Some(m) = run.modify {
+        (m.get_data_fn())(monitor.clone(), &run.modify_params);
+    }
+    if let Some(path) = &run.save {
+        let saved = match monitor.lock() {
+            Ok(lk) => lk.maze.maze.save(path),
+            Err(p) => print::maze_panic!("Save thread panic: {}", p),
+        };
+        if let Err(e) = saved {
+            return Err(format!("could not save maze[{}]: {}", path.display(), e));
+        }
+    }
+    (run.solve.get_data_fn())(monitor.clone(), &run.solve_params);
+    match Arc::into_inner(monitor) {
+        Some(a) => match Mutex::into_inner(a) {
+            Ok(solver) => Ok(solver.maze.maze),
+            Err(_) => print::maze_panic!("printing cannot progress without lock"),
+        },
+        None => print::maze_panic!("printing cannot progress without lock"),
+    }
+}
+
+///
+/// Argument parsing. Everything the TUI understands goes through the shared tables parser.
+///
+fn set_cli_args(args: &[String]) -> Result<CliRun, String> {
+    let mut cli = CliRun {
+        run: tables::HistoryRunner::new(),
+        color: false,
+    };
+    cli.run.args.seed = thread_rng().gen();
+    let mut words = args.iter();
+    while let Some(flag) = words.next() {
+        if flag == "-color" {
+            cli.color = true;
+            continue;
+        }
+        let arg = match words.next() {
+            Some(a) => a,
+            None => return Err(format!("flag[{}] with missing arg[?]", flag)),
+        };
+        match flag.as_str() {
+            "-ba" | "-sa" | "-record" => {
+                return Err(format!("flag[{}] only works with the TUI playback", flag))
+            }
+            _ => match tables::match_flag(flag) {
+                Some(f) => tables::set_arg(&mut cli.run, &tables::FlagArg { flag: f, arg })?,
+                None => return Err(format!("unknown flag[{}].", flag)),
+            },
+        }
+    }
+    cli.run.args.odd_rows = cli.run.rows.unwrap_or(maze::DEFAULT_ROWS);
+    cli.run.args.odd_cols = cli.run.cols.unwrap_or(maze::DEFAULT_COLS);
+    Ok(cli)
+}
+
+///
+/// Printing borrows the same square decoding as the TUI and turns each cell into ANSI text.
+///
+fn print_maze(maze: &maze::Blueprint, color: bool) -> io::Result<()> {
+    let mut out = BufWriter::new(io::stdout().lock());
+    if maze.is_mini() {
+        // Two maze rows share one line of half blocks so later rows overwrite the shared line.
+        let row_len = (maze.rows / 2) as usize;
+        let mut lines = vec![vec![Cell::default(); maze.cols as usize]; row_len + 1];
+        for y in 0..row_len * 2 + 1 {
+            for (x, cell) in lines[y / 2].iter_mut().enumerate() {
+                *cell = solve::decode_mini_path(
+                    maze,
+                    maze::Point {
+                        row: y as i32,
+                        col: x as i32,
+                    },
+                );
+            }
+        }
+        for line in lines.iter() {
+            write_line(&mut out, line, color)?;
+        }
+    } else {
+        let wall_row = maze.wall_row();
+        for row in maze.buf.chunks(maze.cols as usize) {
+            let line: Vec<Cell> = row
+                .iter()
+                .map(|&square| solve::decode_square(wall_row, square))
+                .collect();
+            write_line(&mut out, &line, color)?;
+        }
+    }
+    out.flush()
+}
+
+fn write_line(out: &mut impl Write, line: &[Cell], color: bool) -> io::Result<()> {
+    let mut text = String::new();
+    for cell in line {
+        if !color {
+            text.push_str(&cell.symbol);
+            continue;
+        }
+        let mut styled = false;
+        if cell.modifier.contains(Modifier::BOLD) {
+            text.push_str("\x1b[1m");
+            styled = true;
+        }
+        styled |= push_color(&mut text, cell.fg, 38);
+        styled |= push_color(&mut text, cell.bg, 48);
+        text.push_str(&cell.symbol);
+        if styled {
+            text.push_str("\x1b[0m");
+        }
+    }
+    writeln!(out, "{}", text)
+}
+
+// The layer is 38 for foreground and 48 for background. Reset colors leave the terminal default.
+fn push_color(text: &mut String, color: Color, layer: u8) -> bool {
+    match color {
+        Color::Rgb(r, g, b) => text.push_str(&format!("\x1b[{};2;{};{};{}m", layer, r, g, b)),
+        Color::Indexed(i) => text.push_str(&format!("\x1b[{};5;{}m", layer, i)),
+        _ => return false,
+    }
+    true
+}
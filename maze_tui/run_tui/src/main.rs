@@ -2,7 +2,8 @@ mod run;
 mod tui;
 
 fn main() -> tui::Result<()> {
-    let status = run::run();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let status = run::run(args);
     status?;
     Ok(())
 }
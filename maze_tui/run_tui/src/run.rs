@@ -1,22 +1,32 @@
 use crate::tui;
-use builders::build;
+use builders::{build, validate};
 use crossterm::event::KeyCode;
-use rand::{distributions::Bernoulli, distributions::Distribution, thread_rng};
+use rand::{distributions::Bernoulli, distributions::Distribution, thread_rng, Rng};
 use ratatui::{
     prelude::{CrosstermBackend, Rect, Terminal},
     widgets::ScrollDirection,
 };
-use std::{error, fmt, rc::Rc, sync::Arc, sync::Mutex};
+use solvers::stats;
+use std::{cmp, error, fmt, rc::Rc, sync::Arc, sync::Mutex, time::Duration};
 use tui_textarea::{Input, Key};
 
-static VALID_FLAGS: &str = "VALID FLAGS:[-b][-ba][-s][-sa][-w][-m]";
-static VALID_ARGS: [(&str, &str); 6] = [
+static VALID_FLAGS: &str =
+    "VALID FLAGS:[-b][-ba][-s][-sa][-w][-m][-r][-c][-seed][-save][-load][-mask][-record][-replay]";
+static VALID_ARGS: [(&str, &str); 14] = [
     ("-b", "see BUILDER FLAG section"),
     ("-m", "see MODIFICATION FLAG section"),
     ("-w", "see WALL FLAG section"),
     ("-s", "see SOLVER FLAG section"),
     ("-sa", "see SOLVER ANIMATION section"),
     ("-ba", "see BUILDER ANIMATION section"),
+    ("-seed", "see SEED FLAG section"),
+    ("-save", "see SAVE FLAG section"),
+    ("-load", "see LOAD FLAG section"),
+    ("-mask", "see MASK FLAG section"),
+    ("-record", "see RECORD FLAG section"),
+    ("-replay", "see REPLAY FLAG section"),
+    ("-r", "see DIMENSION FLAG section"),
+    ("-c", "see DIMENSION FLAG section"),
 ];
 
 #[derive(Debug)]
@@ -46,6 +56,8 @@ struct Playback {
     solve_tape: maze::Tape,
     forward: bool,
     pause: bool,
+    viewport: tui::Viewport,
+    stats: stats::Stats,
 }
 
 ///
@@ -53,13 +65,20 @@ struct Playback {
 ///
 /// The main render loop from the home page. This loop is relatively simple. When the more
 /// complex functionality of a maze animation is requested we will hand that off to another fn.
-pub fn run() -> tui::Result<()> {
+/// Flags from the shell are typed into the command line for us and run before the home page.
+// Shell arguments arrive already split so they are parsed as they are. Splitting them again would
+// break any argument that holds a space, like a mask of text or a file path.
+pub fn run(args: Vec<String>) -> tui::Result<()> {
     let backend = CrosstermBackend::new(std::io::stdout());
     let terminal = Terminal::new(backend)?;
     let events = tui::EventHandler::new(4.0);
     let mut tui = tui::Tui::new(terminal, events);
     tui.enter()?;
     let mut play = new_home_tape(tui.padded_frame());
+    if !args.is_empty() {
+        tui.cmd.insert_str(args.join(" "));
+        start_run(&mut tui, &play, &args)?;
+    }
     'render: while let Some(ev) = tui.events.next() {
         match ev {
             tui::Pack::Resize(_, _) => {
@@ -71,25 +90,18 @@ pub fn run() -> tui::Result<()> {
                 Input { key: Key::Up, .. } => tui.scroll(ScrollDirection::Backward),
                 Input {
                     key: Key::Enter, ..
-                } => match set_command_args(tui.cmd.lines()[0].to_string(), &mut tui) {
-                    Ok(run) => {
-                        render_maze(run, &mut tui)?;
-                    }
-                    Err(msg) => 'reading_message: loop {
-                        if let Some(ev) = tui.events.next() {
-                            match ev {
-                                tui::Pack::Render => {
-                                    tui.error_popup(&msg, tui::SolveFrame { maze: &play.maze })?;
-                                }
-                                _ => break 'reading_message,
-                            }
-                        }
-                    },
-                },
+                } => {
+                    let words: Vec<String> = tui.cmd.lines()[0]
+                        .split_whitespace()
+                        .map(String::from)
+                        .collect();
+                    start_run(&mut tui, &play, &words)?
+                }
                 input => {
                     tui.cmd_input(input);
                 }
             },
+            tui::Pack::Mouse(_) => {}
             tui::Pack::Render => {
                 if play.pause {
                     play.forward = !play.forward;
@@ -97,7 +109,10 @@ pub fn run() -> tui::Result<()> {
                 } else if !play.solve_delta() {
                     play.forward = true;
                 }
-                tui.home(tui::SolveFrame { maze: &play.maze })?;
+                tui.home(tui::SolveFrame {
+                    maze: &play.maze,
+                    view: tui::View::default(),
+                })?;
             }
         }
     }
@@ -105,79 +120,141 @@ pub fn run() -> tui::Result<()> {
     Ok(())
 }
 
+// Runs whatever is in the command line or shows why it can't over the home page until a key press.
+fn start_run(tui: &mut tui::Tui, home: &Playback, words: &[String]) -> tui::Result<()> {
+    match set_command_args(words, tui)
+        .and_then(|mut run| new_tape(&mut run).map(|play| (run, play)))
+    {
+        Ok((run, play)) => render_maze(run, play, tui),
+        Err(msg) => 'reading_message: loop {
+            if let Some(ev) = tui.events.next() {
+                match ev {
+                    tui::Pack::Render => {
+                        tui.error_popup(
+                            &msg,
+                            tui::SolveFrame {
+                                maze: &home.maze,
+                                view: tui::View::default(),
+                            },
+                        )?;
+                    }
+                    tui::Pack::Mouse(_) => {}
+                    _ => break 'reading_message Ok(()),
+                }
+            }
+        },
+    }
+}
+
 // Keeping the three loops visible in one function like this makes it easier to reason about
 // playing the animation forward or in reverse. The handle_press function can mutate the
 // play direction but needed to extract repetitive logic that made this function harder to read.
-fn render_maze(this_run: tables::HistoryRunner, tui: &mut tui::Tui) -> tui::Result<()> {
+// A timeline jump may also change the process and then we hop to the loop that plays it.
+// Entering either loop applies the speed requested for that phase, if any.
+fn render_maze(
+    this_run: tables::HistoryRunner,
+    mut play: Playback,
+    tui: &mut tui::Tui,
+) -> tui::Result<()> {
     let render_space = tui.inner_maze_rect();
-    let mut play = new_tape(&this_run);
+    // Each phase starts from its own speed flag or, lacking one, the speed in place before this run.
+    let home_delta = tui.events.delta();
     'rendering: loop {
+        let mut process = tui::Process::Building;
+        tui.events
+            .set_delta(this_run.build_speed.map_or(home_delta, |s| {
+                Duration::from_micros(this_run.build.get_speed(s))
+            }));
         'building: while let Some(ev) = tui.events.next() {
             match ev {
                 tui::Pack::Press(ev) => {
                     if !handle_press(
                         tui,
                         ev.code,
-                        tui::Process::Building,
+                        &mut process,
                         &this_run,
                         &mut play,
                         &render_space,
                     ) {
                         break 'rendering;
                     }
+                    if process == tui::Process::Solving {
+                        break 'building;
+                    }
                 }
                 tui::Pack::Render => {
                     if !play.build_delta() {
                         break 'building;
                     }
+                    let view = play.fit_view(process, render_space[0]);
                     tui.render_maze_frame(
                         tui::BuildFrame {
-                            maze: &mut play.maze,
+                            maze: &play.maze,
+                            view,
                         },
                         &render_space,
                         play.forward,
                         play.pause,
+                        this_run.args.seed,
+                        play.timeline(process),
                     )?;
                 }
+                tui::Pack::Mouse(ev) => play.viewport.mouse(ev),
                 tui::Pack::Resize(_, _) => break 'rendering,
             }
         }
+        process = tui::Process::Solving;
+        tui.events
+            .set_delta(this_run.solve_speed.map_or(home_delta, |s| {
+                Duration::from_micros(this_run.solve.get_speed(s))
+            }));
         'solving: while let Some(ev) = tui.events.next() {
             match ev {
                 tui::Pack::Press(ev) => {
                     if !handle_press(
                         tui,
                         ev.code,
-                        tui::Process::Solving,
+                        &mut process,
                         &this_run,
                         &mut play,
                         &render_space,
                     ) {
                         break 'rendering;
                     }
+                    if process == tui::Process::Building {
+                        continue 'rendering;
+                    }
                 }
                 tui::Pack::Render => {
                     if !play.solve_delta() {
                         break 'solving;
                     }
+                    let view = play.fit_view(process, render_space[0]);
                     tui.render_maze_frame(
-                        tui::SolveFrame { maze: &play.maze },
+                        tui::SolveFrame {
+                            maze: &play.maze,
+                            view,
+                        },
                         &render_space,
                         play.forward,
                         play.pause,
+                        this_run.args.seed,
+                        play.timeline(process),
                     )?;
                 }
+                tui::Pack::Mouse(ev) => play.viewport.mouse(ev),
                 tui::Pack::Resize(_, _) => break 'rendering,
             }
         }
     }
+    tui.events.set_delta(home_delta);
     Ok(())
 }
 
 fn handle_press(
     tui: &mut tui::Tui,
     ev: crossterm::event::KeyCode,
-    process: tui::Process,
+    process: &mut tui::Process,
     args: &tables::HistoryRunner,
     play: &mut Playback,
     render_space: &Rc<[Rect]>,
@@ -186,9 +263,10 @@ fn handle_press(
         KeyCode::Char('i') => {
             if handle_reader(
                 tui,
-                process,
+                *process,
                 args.build.get_description(),
                 &play.maze,
+                play.viewport.view,
                 render_space,
             )
             .is_err()
@@ -196,11 +274,23 @@ fn handle_press(
                 return false;
             }
         }
+        KeyCode::Char('t') => {
+            let msg = play.stats.to_string();
+            if handle_report(tui, *process, play, render_space, 't', "stats", &msg).is_err() {
+                return false;
+            }
+        }
+        KeyCode::Char('v') => {
+            let msg = validate::validate(&play.maze).to_string();
+            if handle_report(tui, *process, play, render_space, 'v', "validate", &msg).is_err() {
+                return false;
+            }
+        }
         KeyCode::Char(' ') => play.pause = !play.pause,
         KeyCode::Right => {
             play.forward = true;
             play.pause = true;
-            match process {
+            match *process {
                 tui::Process::Building => play.build_step(),
                 tui::Process::Solving => play.solve_step(),
             };
@@ -208,22 +298,55 @@ fn handle_press(
         KeyCode::Left => {
             play.forward = false;
             play.pause = true;
-            match process {
+            match *process {
                 tui::Process::Building => play.build_step(),
                 tui::Process::Solving => play.solve_step(),
             };
         }
+        KeyCode::Char(c @ '0'..='9') => {
+            play.seek(*process, f64::from(c as u8 - b'0') * 10.0);
+        }
+        KeyCode::Home => *process = play.jump(0),
+        KeyCode::End => *process = play.jump(usize::MAX),
+        KeyCode::PageUp => {
+            let step = play.timeline(*process);
+            *process = play.jump(
+                (step.build_step + step.solve_step)
+                    .saturating_sub((step.build_len + step.solve_len) / 10),
+            );
+        }
+        KeyCode::PageDown => {
+            let step = play.timeline(*process);
+            *process = play
+                .jump(step.build_step + step.solve_step + (step.build_len + step.solve_len) / 10);
+        }
+        KeyCode::Char('b') => *process = play.jump(play.build_tape.len()),
+        KeyCode::Char('w') => play.viewport.pan(-pan_step(render_space[0].height), 0),
+        KeyCode::Char('s') => play.viewport.pan(pan_step(render_space[0].height), 0),
+        KeyCode::Char('a') => play.viewport.pan(0, -pan_step(render_space[0].width)),
+        KeyCode::Char('d') => play.viewport.pan(0, pan_step(render_space[0].width)),
+        KeyCode::Char('f') => play.viewport.follow = !play.viewport.follow,
+        KeyCode::Char('-') => play.viewport.zoom(-1, &play.maze, render_space[0]),
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            play.viewport.zoom(1, &play.maze, render_space[0])
+        }
         KeyCode::Esc => return false,
         _ => return true,
     }
     true
 }
 
+// A key press pans a quarter of the view so crossing a huge maze takes a handful of presses.
+fn pan_step(cells: u16) -> i32 {
+    cmp::max(1, cells as i32 / 4)
+}
+
 fn handle_reader(
     tui: &mut tui::Tui,
     process: tui::Process,
     description: &str,
     maze: &maze::Blueprint,
+    view: tui::View,
     render_space: &Rc<[Rect]>,
 ) -> tui::Result<()> {
     let mut scroll = tui::Scroller::default();
@@ -238,8 +361,45 @@ fn handle_reader(
                     _ => {}
                 },
                 tui::Pack::Render => {
-                    tui.info_popup(process, render_space, maze, &mut scroll, description)?;
+                    tui.info_popup(process, render_space, maze, view, &mut scroll, description)?;
+                }
+                tui::Pack::Mouse(_) => {}
+                tui::Pack::Resize(_, _) => return Err(Box::new(Quit::new())),
+            }
+        }
+    }
+    Ok(())
+}
+
+// A report popup closes with the same key that opened it.
+fn handle_report(
+    tui: &mut tui::Tui,
+    process: tui::Process,
+    play: &Playback,
+    render_space: &Rc<[Rect]>,
+    key: char,
+    title: &str,
+    msg: &str,
+) -> tui::Result<()> {
+    'reading: loop {
+        if let Some(k) = tui.events.next() {
+            match k {
+                tui::Pack::Press(k) => match k.code {
+                    KeyCode::Char(c) if c == key => break 'reading,
+                    KeyCode::Esc => return Err(Box::new(Quit::new())),
+                    _ => {}
+                },
+                tui::Pack::Render => {
+                    tui.report_popup(
+                        process,
+                        render_space,
+                        &play.maze,
+                        play.viewport.view,
+                        title,
+                        msg,
+                    )?;
                 }
+                tui::Pack::Mouse(_) => {}
                 tui::Pack::Resize(_, _) => return Err(Box::new(Quit::new())),
             }
         }
@@ -252,25 +412,86 @@ fn handle_reader(
 /// until the maze generation and solving histories have been recorded. Then we decide how
 /// we want to play all of that back with the help of builder and solver decoding functions.
 ///
-// A new tape runs to completion then resets the maze buffer to its starting state.
-fn new_tape(run: &tables::HistoryRunner) -> Playback {
-    let monitor = monitor::Monitor::new(maze::Maze::new(run.args));
-    (run.build.get_fn())(monitor.clone());
+// A new tape runs to completion then resets the maze buffer to its starting state. A loaded maze
+// skips the builder and its starting state is the file contents rather than an empty buffer.
+// A replayed recording was already reset to its starting state when it was recorded.
+// A mask only shapes new mazes because a loaded maze keeps the mask it was saved with.
+fn new_tape(run: &mut tables::HistoryRunner) -> Result<Playback, String> {
+    if let Some(mut replay) = run.replay.take() {
+        replay.maze.offset = run.args.offset;
+        run.args.seed = replay.seed;
+        let mut play = Playback {
+            maze: replay.maze,
+            build_tape: replay.build_history,
+            solve_tape: replay.solve_history,
+            forward: true,
+            pause: false,
+            viewport: tui::Viewport::default(),
+            stats: stats::Stats::default(),
+        };
+        play.set_keyframes();
+        return Ok(play);
+    }
+    let mut maze = match &run.load {
+        Some(blueprint) => maze::Maze::from_blueprint(blueprint.clone(), run.args),
+        None => maze::Maze::new(run.args),
+    };
+    if let (None, Some(shape)) = (&run.load, &run.mask) {
+        if let Err(e) = tables::mask::apply(&mut maze, shape) {
+            return Err(format!(
+                "could not apply mask: {}\npress any key to continue",
+                e
+            ));
+        }
+    }
+    let monitor = monitor::Monitor::new(maze);
+    if run.load.is_none() {
+        (run.build.get_fn())(monitor.clone(), &run.build_params);
+    }
     if let Some(m) = run.modify {
-        m.get_fn()(monitor.clone());
+        m.get_fn()(monitor.clone(), &run.modify_params);
     }
-    (run.solve.get_fn())(monitor.clone());
+    if let Some(path) = &run.save {
+        let saved = match monitor.lock() {
+            Ok(lk) => lk.maze.maze.save(path),
+            Err(p) => print::maze_panic!("Save thread panic: {}", p),
+        };
+        if let Err(e) = saved {
+            return Err(format!(
+                "could not save maze[{}]: {}\npress any key to continue",
+                path.display(),
+                e
+            ));
+        }
+    }
+    (run.solve.get_fn())(monitor.clone(), &run.solve_params);
     match Arc::into_inner(monitor) {
         Some(a) => match Mutex::into_inner(a) {
             Ok(mut solver) => {
-                build::reset_build(&mut solver.maze);
-                Playback {
+                match &run.load {
+                    Some(blueprint) => solver.maze.as_slice_mut().copy_from_slice(&blueprint.buf),
+                    None => build::reset_build(&mut solver.maze),
+                }
+                if let Some(path) = &run.record {
+                    if let Err(e) = solver.maze.save_recording(path) {
+                        return Err(format!(
+                            "could not record run[{}]: {}\npress any key to continue",
+                            path.display(),
+                            e
+                        ));
+                    }
+                }
+                let mut play = Playback {
                     maze: solver.maze.maze,
                     build_tape: solver.maze.build_history,
                     solve_tape: solver.maze.solve_history,
                     forward: true,
                     pause: false,
-                }
+                    viewport: tui::Viewport::default(),
+                    stats: stats::Stats::default(),
+                };
+                play.set_keyframes();
+                Ok(play)
             }
             Err(_) => print::maze_panic!("rendering cannot progress without lock"),
         },
@@ -282,11 +503,11 @@ fn new_tape(run: &tables::HistoryRunner) -> Playback {
 fn new_home_tape(rect: Rect) -> Playback {
     let run_bg = set_random_args(&rect);
     let bg_maze = monitor::Monitor::new(maze::Maze::new(run_bg.args));
-    (run_bg.build.get_fn())(bg_maze.clone());
+    (run_bg.build.get_fn())(bg_maze.clone(), &run_bg.build_params);
     if let Some(m) = run_bg.modify {
-        (m.get_fn())(bg_maze.clone());
+        (m.get_fn())(bg_maze.clone(), &run_bg.modify_params);
     }
-    (run_bg.solve.get_fn())(bg_maze.clone());
+    (run_bg.solve.get_fn())(bg_maze.clone(), &run_bg.solve_params);
     match Arc::into_inner(bg_maze) {
         Some(a) => match Mutex::into_inner(a) {
             Ok(mut solver) => {
@@ -297,6 +518,8 @@ fn new_home_tape(rect: Rect) -> Playback {
                     solve_tape: solver.maze.solve_history,
                     forward: true,
                     pause: false,
+                    viewport: tui::Viewport::default(),
+                    stats: stats::Stats::default(),
                 }
             }
             Err(_) => print::maze_panic!("rendering cannot progress without lock"),
@@ -308,20 +531,22 @@ fn new_home_tape(rect: Rect) -> Playback {
 ///
 /// Argument parsing from the tui-textarea or random generation if empty
 ///
-pub fn set_command_args(cmd: String, tui: &mut tui::Tui) -> Result<tables::HistoryRunner, String> {
-    if cmd.is_empty() {
+pub fn set_command_args(
+    words: &[String],
+    tui: &mut tui::Tui,
+) -> Result<tables::HistoryRunner, String> {
+    if words.is_empty() {
         return Ok(set_random_args(&tui.inner_maze_rect()[0]));
     }
     let dimensions = tui.inner_dimensions();
     let mut run = tables::HistoryRunner::new();
-    run.args.odd_rows = dimensions.rows;
-    run.args.odd_cols = dimensions.cols;
     run.args.offset = dimensions.offset;
+    run.args.seed = thread_rng().gen();
     let mut prev_flag: &str = "";
     let mut process_current = false;
-    for a in cmd.split_whitespace() {
+    for a in words.iter().map(String::as_str) {
         if process_current {
-            if let Err(msg) = set_arg(
+            if let Err(msg) = tables::set_arg(
                 &mut run,
                 &tables::FlagArg {
                     flag: prev_flag,
@@ -353,30 +578,15 @@ pub fn set_command_args(cmd: String, tui: &mut tui::Tui) -> Result<tables::Histo
             get_arg_section(prev_flag)
         ));
     }
-    if run.args.style == maze::MazeStyle::Mini {
-        run.args.odd_rows *= 2;
-    }
+    // Explicit dimensions count maze squares and may be far larger than the terminal.
+    run.args.odd_rows = run.rows.unwrap_or(match run.args.style {
+        maze::MazeStyle::Mini => dimensions.rows * 2,
+        _ => dimensions.rows,
+    });
+    run.args.odd_cols = run.cols.unwrap_or(dimensions.cols);
     Ok(run)
 }
 
-fn set_arg(run: &mut tables::HistoryRunner, args: &tables::FlagArg) -> Result<(), String> {
-    match args.flag {
-        "-b" => tables::match_builder(args.arg)
-            .map(|func_pair| run.build = func_pair)
-            .ok_or(err_string(args)),
-        "-m" => tables::match_modifier(args.arg)
-            .map(|mod_tuple| run.modify = Some(mod_tuple))
-            .ok_or(err_string(args)),
-        "-s" => tables::match_solver(args.arg)
-            .map(|solve_tuple| run.solve = solve_tuple)
-            .ok_or(err_string(args)),
-        "-w" => tables::match_walls(args.arg)
-            .map(|wall_style| run.args.style = wall_style)
-            .ok_or(err_string(args)),
-        _ => Err(err_string(args)),
-    }
-}
-
 fn set_random_args(rect: &Rect) -> tables::HistoryRunner {
     let mut rng = thread_rng();
     let mut this_run = tables::HistoryRunner::new();
@@ -386,6 +596,7 @@ fn set_random_args(rect: &Rect) -> tables::HistoryRunner {
         add_rows: rect.y as i32,
         add_cols: rect.x as i32,
     };
+    this_run.args.seed = rng.gen();
     let modification_probability = Bernoulli::new(0.2);
     this_run.args.style = maze::MazeStyle::get_random(&mut rng);
     this_run.build = tables::BuildHistoryType::get_random(&mut rng);
@@ -403,10 +614,6 @@ fn set_random_args(rect: &Rect) -> tables::HistoryRunner {
     this_run
 }
 
-fn err_string(args: &tables::FlagArg) -> String {
-    format!("invalid flag[{}] arg[{}] combo", args.flag, args.arg)
-}
-
 fn get_arg_section(flag: &str) -> &'static str {
     VALID_ARGS
         .iter()
@@ -456,6 +663,64 @@ impl Playback {
         false
     }
 
+    // Both Tapes must start from the maze buffer as it is right after the Playback is made. The
+    // stats measure the maze as it stands after the solve so the start and finish are known.
+    fn set_keyframes(&mut self) {
+        let built = self.build_tape.set_keyframes(&self.maze);
+        self.stats = stats::measure(&self.solve_tape.set_keyframes(&built));
+    }
+
+    // Seeking lands just before a frame plays so only a forward step will show that frame.
+    fn seek(&mut self, process: tui::Process, percent: f64) {
+        match process {
+            tui::Process::Building => self.build_tape.seek_percent(percent, &mut self.maze),
+            tui::Process::Solving => self.solve_tape.seek_percent(percent, &mut self.maze),
+        };
+        self.forward = true;
+    }
+
+    // The build Tape is finished for the whole solving process so it counts as fully played.
+    fn timeline(&self, process: tui::Process) -> tui::Timeline {
+        tui::Timeline {
+            build_step: match process {
+                tui::Process::Building => self.build_tape.pos(),
+                tui::Process::Solving => self.build_tape.len(),
+            },
+            build_len: self.build_tape.len(),
+            solve_step: match process {
+                tui::Process::Building => 0,
+                tui::Process::Solving => self.solve_tape.pos(),
+            },
+            solve_len: self.solve_tape.len(),
+        }
+    }
+
+    // Positions both Tapes for a step on the whole run timeline and reports which process should
+    // play from there. Keyframes hold the entire maze so the last seek decides the buffer.
+    fn jump(&mut self, step: usize) -> tui::Process {
+        let build_len = self.build_tape.len();
+        if step < build_len {
+            self.solve_tape.seek(0, &mut self.maze);
+            self.build_tape.seek(step, &mut self.maze);
+            return tui::Process::Building;
+        }
+        self.build_tape
+            .seek(build_len.saturating_sub(1), &mut self.maze);
+        self.solve_tape.seek(step - build_len, &mut self.maze);
+        tui::Process::Solving
+    }
+
+    // The square most recently touched by the playing Tape is where a followed view centers.
+    fn fit_view(&mut self, process: tui::Process, area: Rect) -> tui::View {
+        let focus = match process {
+            tui::Process::Building => self.build_tape.cur_step(),
+            tui::Process::Solving => self.solve_tape.cur_step(),
+        }
+        .and_then(|step| step.first())
+        .map(|delta| delta.id);
+        self.viewport.fit(&self.maze, focus, area)
+    }
+
     fn build_delta(&mut self) -> bool {
         if self.pause {
             return true;
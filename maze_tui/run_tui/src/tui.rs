@@ -1,15 +1,18 @@
 use builders::build;
 use crossbeam_channel::{self, unbounded};
-use crossterm::event::{self, DisableMouseCapture, EnableMouseCapture, KeyCode, KeyEvent};
+use crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, KeyCode, KeyEvent, KeyModifiers, MouseEvent,
+    MouseEventKind,
+};
 use crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen};
 use ratatui::{
-    buffer::Buffer,
+    buffer::{Buffer, Cell},
     layout::{Constraint, Direction, Layout},
     prelude::{Alignment, Color, Modifier, Rect},
     style::Style,
     symbols::border::Set,
     widgets::{
-        Block, BorderType, Borders, Clear, Paragraph, ScrollDirection, Scrollbar,
+        Block, BorderType, Borders, Clear, LineGauge, Paragraph, ScrollDirection, Scrollbar,
         ScrollbarOrientation, ScrollbarState, Widget, Wrap,
     },
 };
@@ -17,6 +20,10 @@ use solvers::solve;
 use tui_textarea::{Input, TextArea};
 
 use std::rc::Rc;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
 use std::{
     cmp, thread,
     time::{Duration, Instant},
@@ -29,10 +36,10 @@ pub type Err = Box<dyn std::error::Error>;
 pub type Result<T> = std::result::Result<T, Err>;
 
 static INSTRUCTIONS: &str = include_str!("../../res/instructions.txt");
-static INSTRUCTIONS_LINE_COUNT: usize = 70;
+static INSTRUCTIONS_LINE_COUNT: usize = 154;
 static DESCRIPTION_LINE_COUNT: usize = 50;
-static POPUP_INSTRUCTIONS: &str =
-    "[i]info [ESC]exit [SPACE]play/pause\n[←/→]backstep/nextstep [</>]slower/faster";
+static POPUP_INSTRUCTIONS: &str = "[i]info [ESC]exit [SPACE]play/pause [0-9]jump [b]solve\n\
+    [←/→]step [</>]speed [HOME/END]start/end [PGUP/PGDN]±10%";
 const RED_PAUSE: Color = Color::Rgb(201, 77, 83);
 const GREEN_FORWARD: Color = Color::Rgb(77, 201, 81);
 const BLUE_REVERSE: Color = Color::Rgb(42, 111, 222);
@@ -58,8 +65,15 @@ static REVERSE_INDICICATOR: Set = Set {
 };
 const MAX_DURATION: Duration = Duration::from_secs(5);
 const MIN_DURATION: Duration = Duration::from_millis(1);
+const MOUSE_SCROLL: i32 = 3;
+const MAX_ZOOM_IN: i32 = 3;
+const MAX_ZOOM_OUT: i32 = -3;
+// Quadrant blocks indexed by 0bBottomRightBottomLeftTopRightTopLeft filled wall quadrants.
+static QUADRANTS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub enum Process {
     Building,
     Solving,
@@ -69,13 +83,16 @@ pub enum Process {
 #[derive(Debug)]
 pub enum Pack {
     Press(KeyEvent),
+    Mouse(MouseEvent),
     Resize((), ()),
     Render,
 }
 
+// The time between renders in microseconds is shared so playback can set a speed for each phase.
 #[derive(Debug)]
 pub struct EventHandler {
     pub receiver: crossbeam_channel::Receiver<Pack>,
+    delta_micros: Arc<AtomicU64>,
 }
 
 pub struct Tui<'a> {
@@ -98,12 +115,40 @@ pub struct Scroller {
     pub pos: usize,
 }
 
+// The build and solve Tapes play back to back so they share one timeline under the maze.
+#[derive(Clone, Copy, Default)]
+pub struct Timeline {
+    pub build_step: usize,
+    pub build_len: usize,
+    pub solve_step: usize,
+    pub solve_len: usize,
+}
+
+// The part of a maze a frame draws. The origin counts terminal cells at the current zoom so a
+// mini maze scrolls by lines of half blocks rather than by maze rows. A positive zoom draws each
+// square as a block of zoom + 1 cells and a negative zoom packs 2^-zoom squares into each cell.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct View {
+    pub origin: maze::Point,
+    pub zoom: i32,
+}
+
+// The window into a maze that may be larger than the terminal.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Viewport {
+    pub view: View,
+    pub follow: bool,
+    drag: Option<maze::Point>,
+}
+
 pub struct BuildFrame<'a> {
     pub maze: &'a maze::Blueprint,
+    pub view: View,
 }
 
 pub struct SolveFrame<'a> {
     pub maze: &'a maze::Blueprint,
+    pub view: View,
 }
 
 impl Tui<'_> {
@@ -356,13 +401,26 @@ impl Tui<'_> {
         process: Process,
         rect: &Rc<[Rect]>,
         replay_maze: &maze::Blueprint,
+        view: View,
         scroll: &mut Scroller,
         msg: &str,
     ) -> Result<()> {
         self.terminal.draw(|f| {
             match process {
-                Process::Building => f.render_widget(BuildFrame { maze: replay_maze }, rect[0]),
-                Process::Solving => f.render_widget(SolveFrame { maze: replay_maze }, rect[0]),
+                Process::Building => f.render_widget(
+                    BuildFrame {
+                        maze: replay_maze,
+                        view,
+                    },
+                    rect[0],
+                ),
+                Process::Solving => f.render_widget(
+                    SolveFrame {
+                        maze: replay_maze,
+                        view,
+                    },
+                    rect[0],
+                ),
             }
             let overall_layout = Layout::default()
                 .direction(Direction::Vertical)
@@ -410,6 +468,62 @@ impl Tui<'_> {
         Ok(())
     }
 
+    // Reports are a handful of short lines so the popup fits them rather than the screen.
+    pub fn report_popup(
+        &mut self,
+        process: Process,
+        rect: &Rc<[Rect]>,
+        replay_maze: &maze::Blueprint,
+        view: View,
+        title: &str,
+        msg: &str,
+    ) -> Result<()> {
+        self.terminal.draw(|f| {
+            match process {
+                Process::Building => f.render_widget(
+                    BuildFrame {
+                        maze: replay_maze,
+                        view,
+                    },
+                    rect[0],
+                ),
+                Process::Solving => f.render_widget(
+                    SolveFrame {
+                        maze: replay_maze,
+                        view,
+                    },
+                    rect[0],
+                ),
+            }
+            let area = f.size();
+            let width = cmp::min(
+                area.width,
+                msg.lines().map(|l| l.chars().count()).max().unwrap_or(0) as u16 + 4,
+            );
+            let height = cmp::min(area.height, msg.lines().count() as u16 + 2);
+            let popup = Rect {
+                x: area.x + (area.width - width) / 2,
+                y: area.y + (area.height - height) / 2,
+                width,
+                height,
+            };
+            let popup_report = Paragraph::new(msg)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Double)
+                        .border_style(Style::new().fg(Color::Yellow))
+                        .style(Style::default())
+                        .title(title)
+                        .title_alignment(Alignment::Center),
+                )
+                .alignment(Alignment::Center);
+            f.render_widget(Clear, popup);
+            f.render_widget(popup_report, popup);
+        })?;
+        Ok(())
+    }
+
     pub fn cmd_input(&mut self, input: Input) -> bool {
         self.cmd.input(input)
     }
@@ -420,6 +534,8 @@ impl Tui<'_> {
         rect: &Rc<[Rect]>,
         forward: bool,
         pause: bool,
+        seed: maze::Seed,
+        timeline: Timeline,
     ) -> Result<()> {
         let popup_layout_v = Layout::default()
             .direction(Direction::Vertical)
@@ -437,33 +553,65 @@ impl Tui<'_> {
                 Constraint::Percentage((100 - 50) / 2),
             ])
             .split(popup_layout_v[1])[1];
+        // The timeline runs along the bottom border between the direction arrows in the corners.
+        let timeline_h = Rect {
+            x: popup_layout_h.x + 1,
+            y: popup_layout_h.bottom().saturating_sub(1),
+            width: popup_layout_h.width.saturating_sub(2),
+            height: cmp::min(popup_layout_h.height, 1),
+        };
         let popup_instructions = Paragraph::new(POPUP_INSTRUCTIONS)
-            .block(match (pause, forward) {
-                (true, true) => Block::default()
-                    .borders(Borders::ALL)
-                    .border_set(FORWARD_INDICICATOR)
-                    .border_style(Style::new().fg(RED_PAUSE))
-                    .style(Style::default()),
-                (true, false) => Block::default()
-                    .borders(Borders::ALL)
-                    .border_set(REVERSE_INDICICATOR)
-                    .border_style(Style::new().fg(RED_PAUSE))
-                    .style(Style::default()),
-                (false, true) => Block::default()
-                    .borders(Borders::ALL)
-                    .border_set(FORWARD_INDICICATOR)
-                    .border_style(Style::new().fg(GREEN_FORWARD))
-                    .style(Style::default()),
-                (false, false) => Block::default()
-                    .borders(Borders::ALL)
-                    .border_set(REVERSE_INDICICATOR)
-                    .border_style(Style::new().fg(BLUE_REVERSE))
-                    .style(Style::default()),
-            })
+            .block(
+                match (pause, forward) {
+                    (true, true) => Block::default()
+                        .borders(Borders::ALL)
+                        .border_set(FORWARD_INDICICATOR)
+                        .border_style(Style::new().fg(RED_PAUSE))
+                        .style(Style::default()),
+                    (true, false) => Block::default()
+                        .borders(Borders::ALL)
+                        .border_set(REVERSE_INDICICATOR)
+                        .border_style(Style::new().fg(RED_PAUSE))
+                        .style(Style::default()),
+                    (false, true) => Block::default()
+                        .borders(Borders::ALL)
+                        .border_set(FORWARD_INDICICATOR)
+                        .border_style(Style::new().fg(GREEN_FORWARD))
+                        .style(Style::default()),
+                    (false, false) => Block::default()
+                        .borders(Borders::ALL)
+                        .border_set(REVERSE_INDICICATOR)
+                        .border_style(Style::new().fg(BLUE_REVERSE))
+                        .style(Style::default()),
+                }
+                .title(format!("seed:{}", seed))
+                .title_alignment(Alignment::Center),
+            )
             .alignment(Alignment::Center);
+        let total = timeline.build_len + timeline.solve_len;
+        let progress = LineGauge::default()
+            .ratio(if total == 0 {
+                0.0
+            } else {
+                (timeline.build_step + timeline.solve_step) as f64 / total as f64
+            })
+            .label(format!(
+                "build {}/{} solve {}/{}",
+                timeline.build_step, timeline.build_len, timeline.solve_step, timeline.solve_len
+            ))
+            .gauge_style(
+                Style::default()
+                    .fg(if timeline.build_step < timeline.build_len {
+                        Color::Yellow
+                    } else {
+                        Color::Cyan
+                    })
+                    .bg(Color::DarkGray),
+            );
         self.terminal.draw(|f| {
             f.render_widget(frame, rect[0]);
             f.render_widget(popup_instructions, popup_layout_h);
+            f.render_widget(progress, timeline_h);
         })?;
         Ok(())
     }
@@ -472,12 +620,16 @@ impl Tui<'_> {
 impl EventHandler {
     /// Constructs a new instance of [`EventHandler`].
     pub fn new(delta_rate: f64) -> Self {
-        let mut deltas = Duration::from_secs_f64(1.0 / delta_rate);
+        let delta_micros = Arc::new(AtomicU64::new(
+            Duration::from_secs_f64(1.0 / delta_rate).as_micros() as u64,
+        ));
+        let shared_micros = delta_micros.clone();
         let (sender, receiver) = unbounded();
         let sender = sender.clone();
         thread::spawn(move || {
             let mut last_delta = Instant::now();
             loop {
+                let mut deltas = Duration::from_micros(shared_micros.load(Ordering::Relaxed));
                 let elapsed = last_delta.elapsed();
                 // Using a timeout gives the CPU some time to park this thread with any remaining
                 // time we have until the next render while ensuring we don't miss keys.
@@ -488,25 +640,35 @@ impl EventHandler {
                 };
                 if event::poll(timeout).expect("polling error") {
                     match event::read().expect("event error") {
-                        CtEvent::Key(e) => {
-                            if e.kind == event::KeyEventKind::Press {
-                                match e.code {
-                                    KeyCode::Char('>') => {
-                                        deltas = match deltas.checked_div(2) {
-                                            Some(t) => t,
-                                            None => MIN_DURATION,
-                                        };
-                                        deltas = std::cmp::max(deltas, MIN_DURATION);
-                                    }
-                                    KeyCode::Char('<') => {
-                                        deltas =
-                                            std::cmp::min(deltas.saturating_mul(2), MAX_DURATION);
-                                    }
-                                    _ => {
-                                        sender.send(Pack::Press(e)).expect("send press error");
-                                    }
-                                }
+                        CtEvent::Key(e) if e.kind == event::KeyEventKind::Press => match e.code {
+                            KeyCode::Char('>') => {
+                                deltas = match deltas.checked_div(2) {
+                                    Some(t) => t,
+                                    None => MIN_DURATION,
+                                };
+                                deltas = std::cmp::max(deltas, MIN_DURATION);
+                                shared_micros.store(deltas.as_micros() as u64, Ordering::Relaxed);
+                            }
+                            KeyCode::Char('<') => {
+                                deltas = std::cmp::min(deltas.saturating_mul(2), MAX_DURATION);
+                                shared_micros.store(deltas.as_micros() as u64, Ordering::Relaxed);
+                            }
+                            // Most terminals send CTRL-[-] as the same byte as CTRL-[7].
+                            KeyCode::Char('7') if e.modifiers.contains(KeyModifiers::CONTROL) => {
+                                sender
+                                    .send(Pack::Press(KeyEvent::new(
+                                        KeyCode::Char('-'),
+                                        e.modifiers,
+                                    )))
+                                    .expect("send press error");
+                            }
+                            _ => {
+                                sender.send(Pack::Press(e)).expect("send press error");
                             }
+                        },
+                        // Bare pointer motion would flood the channel and nothing uses it.
+                        CtEvent::Mouse(e) if e.kind != MouseEventKind::Moved => {
+                            sender.send(Pack::Mouse(e)).expect("send mouse error");
                         }
                         CtEvent::Resize(_, _) => {
                             sender
@@ -521,85 +683,311 @@ impl EventHandler {
                 }
             }
         });
-        Self { receiver }
+        Self {
+            receiver,
+            delta_micros,
+        }
     }
 
     pub fn next(&self) -> Option<Pack> {
         self.receiver.recv().ok()
     }
+
+    pub fn delta(&self) -> Duration {
+        Duration::from_micros(self.delta_micros.load(Ordering::Relaxed))
+    }
+
+    // The new speed is picked up at the next render so a very slow speed may linger for one frame.
+    pub fn set_delta(&self, delta: Duration) {
+        self.delta_micros
+            .store(clamp_delta(delta).as_micros() as u64, Ordering::Relaxed);
+    }
+}
+
+fn clamp_delta(delta: Duration) -> Duration {
+    delta.clamp(MIN_DURATION, MAX_DURATION)
+}
+
+impl View {
+    // Cells per square along each side when zoomed in.
+    fn scale(&self) -> i32 {
+        cmp::max(1, self.zoom + 1)
+    }
+
+    // Squares per cell along each side when zoomed out. Mini mazes always pack two rows per line.
+    fn shrink(&self, maze: &maze::Blueprint) -> maze::Point {
+        let squares = 1 << cmp::max(0, -self.zoom);
+        maze::Point {
+            row: squares * if maze.is_mini() { 2 } else { 1 },
+            col: squares,
+        }
+    }
+
+    fn cell_of(&self, maze: &maze::Blueprint, p: maze::Point) -> maze::Point {
+        let shrink = self.shrink(maze);
+        maze::Point {
+            row: p.row / shrink.row * self.scale(),
+            col: p.col / shrink.col * self.scale(),
+        }
+    }
+
+    fn square_of(&self, maze: &maze::Blueprint, cell: maze::Point) -> maze::Point {
+        let shrink = self.shrink(maze);
+        maze::Point {
+            row: cell.row / self.scale() * shrink.row,
+            col: cell.col / self.scale() * shrink.col,
+        }
+    }
+
+    // The whole maze measured in terminal cells at this zoom.
+    fn size(&self, maze: &maze::Blueprint) -> maze::Point {
+        let shrink = self.shrink(maze);
+        maze::Point {
+            row: (maze.rows + shrink.row - 1) / shrink.row * self.scale(),
+            col: (maze.cols + shrink.col - 1) / shrink.col * self.scale(),
+        }
+    }
+}
+
+impl Viewport {
+    // Any manual pan means the user wants to look somewhere else so following stops.
+    pub fn pan(&mut self, rows: i32, cols: i32) {
+        self.view.origin.row += rows;
+        self.view.origin.col += cols;
+        self.follow = false;
+    }
+
+    // The square in the middle of the area stays in the middle as the zoom changes.
+    pub fn zoom(&mut self, step: i32, maze: &maze::Blueprint, area: Rect) {
+        let half = maze::Point {
+            row: area.height as i32 / 2,
+            col: area.width as i32 / 2,
+        };
+        let center = self.view.square_of(
+            maze,
+            maze::Point {
+                row: self.view.origin.row + half.row,
+                col: self.view.origin.col + half.col,
+            },
+        );
+        self.view.zoom = (self.view.zoom + step).clamp(MAX_ZOOM_OUT, MAX_ZOOM_IN);
+        let center = self.view.cell_of(maze, center);
+        self.view.origin = maze::Point {
+            row: center.row - half.row,
+            col: center.col - half.col,
+        };
+    }
+
+    // The wheel scrolls, or scrolls sideways with shift held, and dragging pulls the maze along.
+    pub fn mouse(&mut self, ev: MouseEvent) {
+        let at = maze::Point {
+            row: ev.row as i32,
+            col: ev.column as i32,
+        };
+        let sideways = ev.modifiers.contains(KeyModifiers::SHIFT);
+        match ev.kind {
+            MouseEventKind::ScrollDown if sideways => self.pan(0, MOUSE_SCROLL),
+            MouseEventKind::ScrollUp if sideways => self.pan(0, -MOUSE_SCROLL),
+            MouseEventKind::ScrollDown => self.pan(MOUSE_SCROLL, 0),
+            MouseEventKind::ScrollUp => self.pan(-MOUSE_SCROLL, 0),
+            MouseEventKind::ScrollRight => self.pan(0, MOUSE_SCROLL),
+            MouseEventKind::ScrollLeft => self.pan(0, -MOUSE_SCROLL),
+            MouseEventKind::Down(_) => self.drag = Some(at),
+            MouseEventKind::Drag(_) => {
+                if let Some(prev) = self.drag {
+                    self.pan(prev.row - at.row, prev.col - at.col);
+                }
+                self.drag = Some(at);
+            }
+            MouseEventKind::Up(_) => self.drag = None,
+            MouseEventKind::Moved => {}
+        }
+    }
+
+    // Following centers the focus square when there is one. The origin is then kept inside the
+    // maze so panning past an edge or shrinking the maze never shows empty space.
+    pub fn fit(&mut self, maze: &maze::Blueprint, focus: Option<maze::Point>, area: Rect) -> View {
+        if let (true, Some(p)) = (self.follow, focus) {
+            let p = self.view.cell_of(maze, p);
+            self.view.origin = maze::Point {
+                row: p.row - area.height as i32 / 2,
+                col: p.col - area.width as i32 / 2,
+            };
+        }
+        let size = self.view.size(maze);
+        self.view.origin.row = self
+            .view
+            .origin
+            .row
+            .clamp(0, cmp::max(0, size.row - area.height as i32));
+        self.view.origin.col = self
+            .view
+            .origin
+            .col
+            .clamp(0, cmp::max(0, size.col - area.width as i32));
+        self.view
+    }
 }
 
 ///
 /// Supporting implementations
 ///
 impl Widget for BuildFrame<'_> {
-    fn render(self, _area: Rect, buf: &mut Buffer) {
-        if self.maze.is_mini() {
-            let buf_area = buf.area;
-            let row_len = cmp::min(buf_area.height, (self.maze.rows / 2) as u16);
-            let col_len = cmp::min(buf_area.width, self.maze.cols as u16);
-            for y in 0..row_len * 2 + 1 {
-                for x in 0..col_len {
-                    *buf.get_mut(x, y / 2) = build::decode_mini_square(
-                        self.maze,
-                        maze::Point {
-                            row: y as i32,
-                            col: x as i32,
-                        },
-                    );
-                }
-            }
-        } else {
-            let buf_area = buf.area;
-            let row_len = cmp::min(buf_area.height, self.maze.rows as u16);
-            let col_len = cmp::min(buf_area.width, self.maze.cols as u16);
-            let wall_row = &maze::wall_row(self.maze.wall_style_index);
-            let cols = col_len as usize;
-            for y in 0..row_len {
-                for x in 0..col_len {
-                    *buf.get_mut(x, y) = build::decode_square(
-                        wall_row,
-                        self.maze.buf[y as usize * cols + x as usize],
-                    );
-                }
-            }
-        }
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        render_frame(
+            self.maze,
+            self.view,
+            area,
+            buf,
+            build::decode_square,
+            build::decode_mini_square,
+        );
     }
 }
 
 impl Widget for SolveFrame<'_> {
-    fn render(self, _area: Rect, buf: &mut Buffer) {
-        if self.maze.is_mini() {
-            let buf_area = buf.area;
-            let row_len = cmp::min(buf_area.height, (self.maze.rows / 2) as u16);
-            let col_len = cmp::min(buf_area.width, self.maze.cols as u16);
-            for y in 0..row_len * 2 + 1 {
-                for x in 0..col_len {
-                    *buf.get_mut(x, y / 2) = solve::decode_mini_path(
-                        self.maze,
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        render_frame(
+            self.maze,
+            self.view,
+            area,
+            buf,
+            solve::decode_square,
+            solve::decode_mini_path,
+        );
+    }
+}
+
+// Only the squares under the viewport are decoded so a huge maze draws as fast as a small one.
+fn render_frame(
+    maze: &maze::Blueprint,
+    view: View,
+    area: Rect,
+    buf: &mut Buffer,
+    decode: fn(&[char], maze::Square) -> Cell,
+    decode_mini: fn(&maze::Blueprint, maze::Point) -> Cell,
+) {
+    let area = area.intersection(buf.area);
+    let size = view.size(maze);
+    let row_len = cmp::min(area.height as i32, size.row - view.origin.row);
+    let col_len = cmp::min(area.width as i32, size.col - view.origin.col);
+    let wall_row = maze.wall_row();
+    let scale = view.scale();
+    for y in 0..row_len {
+        for x in 0..col_len {
+            let cell = maze::Point {
+                row: view.origin.row + y,
+                col: view.origin.col + x,
+            };
+            let square = view.square_of(maze, cell);
+            let block = maze::Point {
+                row: cell.row % scale,
+                col: cell.col % scale,
+            };
+            *buf.get_mut(area.x + x as u16, area.y + y as u16) = match (view.zoom, maze.is_mini()) {
+                (z, _) if z < 0 => shrink_block(maze, square, view.shrink(maze), decode),
+                // Each line of half blocks shows two maze rows. Decoding the lower row of a line
+                // is enough because the decoders read the row above it for the top half.
+                (_, true) => stretch_mini(
+                    decode_mini(
+                        maze,
                         maze::Point {
-                            row: y as i32,
-                            col: x as i32,
+                            row: cmp::min(square.row + 1, maze.rows - 1),
+                            col: square.col,
                         },
-                    );
+                    ),
+                    block.row,
+                    scale,
+                ),
+                (_, false) => {
+                    let sq = maze.buf[(square.row * maze.cols + square.col) as usize];
+                    stretch(wall_row, sq, decode(wall_row, sq), block)
                 }
+            };
+        }
+    }
+}
+
+// Zooming in repeats a square across a block. Wall lines continue east and south so they still
+// meet their neighbors while letters and arrows only mark the first cell of the block.
+fn stretch(wall_row: &[char], square: maze::Square, mut cell: Cell, block: maze::Point) -> Cell {
+    if block == maze::Point::default() || cell.symbol == "█" || cell.symbol == " " {
+        return cell;
+    }
+    let lines = ((square & maze::WALL_MASK) >> maze::WALL_SHIFT) as usize;
+    let is_line = maze::is_wall(square) && cell.symbol == wall_row[lines].to_string();
+    let east = (maze::EAST_WALL >> maze::WALL_SHIFT) as usize;
+    let south = (maze::SOUTH_WALL >> maze::WALL_SHIFT) as usize;
+    let west = (maze::WEST_WALL >> maze::WALL_SHIFT) as usize;
+    let north = (maze::NORTH_WALL >> maze::WALL_SHIFT) as usize;
+    cell.symbol = match (block.row, block.col) {
+        (0, _) if is_line && lines & east != 0 => wall_row[east | west].to_string(),
+        (_, 0) if is_line && lines & south != 0 => wall_row[north | south].to_string(),
+        _ => " ".to_string(),
+    };
+    cell
+}
+
+// A half block line zoomed in gives its upper lines to the top half and its lower lines to the
+// bottom half. An odd scale leaves the middle line as the original half block.
+fn stretch_mini(mut cell: Cell, line: i32, scale: i32) -> Cell {
+    let half = line * 2 + 1;
+    if half == scale {
+        return cell;
+    }
+    match (cell.symbol.as_str(), half < scale) {
+        ("▀", true) | ("▄", false) => cell.symbol = "█".to_string(),
+        ("▀", false) | ("▄", true) => cell.symbol = " ".to_string(),
+        _ => {}
+    }
+    cell
+}
+
+// Zooming out draws a block of squares as quadrants that are filled when mostly wall. The cell
+// takes the color of the first painted square in the block and the start or finish wins outright.
+fn shrink_block(
+    maze: &maze::Blueprint,
+    corner: maze::Point,
+    shrink: maze::Point,
+    decode: fn(&[char], maze::Square) -> Cell,
+) -> Cell {
+    let wall_row = maze.wall_row();
+    let mut walls = [0; 4];
+    let mut squares = [0; 4];
+    let mut color = Color::Reset;
+    for row in corner.row..cmp::min(corner.row + shrink.row, maze.rows) {
+        for col in corner.col..cmp::min(corner.col + shrink.col, maze.cols) {
+            let square = maze.buf[(row * maze.cols + col) as usize];
+            let quadrant = (((row - corner.row) * 2 / shrink.row) * 2
+                + (col - corner.col) * 2 / shrink.col) as usize;
+            squares[quadrant] += 1;
+            if maze::is_wall(square) {
+                walls[quadrant] += 1;
             }
-        } else {
-            let buf_area = buf.area;
-            let row_len = cmp::min(buf_area.height, self.maze.rows as u16);
-            let col_len = cmp::min(buf_area.width, self.maze.cols as u16);
-            let wall_row = &maze::wall_row(self.maze.wall_style_index);
-            let cols = col_len as usize;
-            for y in 0..row_len {
-                for x in 0..col_len {
-                    *buf.get_mut(x, y) = solve::decode_square(
-                        wall_row,
-                        self.maze.buf[y as usize * cols + x as usize],
-                    );
-                }
+            let cell = decode(wall_row, square);
+            if cell.symbol == "S" || cell.symbol == "F" {
+                return cell;
             }
+            if color == Color::Reset {
+                color = match (cell.symbol.as_str(), maze::is_wall(square)) {
+                    ("█", false) => cell.fg,
+                    _ => cell.bg,
+                };
+            }
+        }
+    }
+    let mut quadrants = 0;
+    for (i, (&w, &n)) in walls.iter().zip(squares.iter()).enumerate() {
+        if n != 0 && w * 2 >= n {
+            quadrants |= 1 << i;
         }
     }
+    Cell {
+        symbol: QUADRANTS[quadrants].to_string(),
+        fg: Color::Reset,
+        bg: color,
+        ..Cell::default()
+    }
 }
 
 impl Scroller {
@@ -616,3 +1004,24 @@ impl Scroller {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tables::{BuildHistoryType, SolveHistoryType};
+
+    // Levels that clamp to the same tick would look identical on screen.
+    fn assert_distinct(speeds: impl Iterator<Item = u64>) {
+        let ticks: Vec<Duration> = speeds
+            .map(|s| clamp_delta(Duration::from_micros(s)))
+            .collect();
+        assert!(ticks.windows(2).all(|w| w[0] > w[1]), "{ticks:?}");
+    }
+
+    #[test]
+    fn every_speed_level_has_its_own_tick() {
+        assert_distinct((1..=7).map(|i| BuildHistoryType::Prim.get_speed(i)));
+        assert_distinct((1..=7).map(|i| SolveHistoryType::DfsHunt.get_speed(i)));
+        assert_distinct((1..=7).map(|i| SolveHistoryType::Distance.get_speed(i)));
+    }
+}
@@ -0,0 +1,836 @@
+use crate::solve;
+use maze;
+use print;
+
+use rand::prelude::*;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::thread;
+
+const BURST: usize = 4;
+// Moves are scaled so a euclidean estimate can rank squares that fall between whole moves.
+const SCALE: u64 = 1000;
+const OPEN_MASK: solve::ThreadPaint = 0x7F7F7F;
+
+// The estimate of the moves left to the nearest finish. Zero turns the search back into a uniform
+// cost search that spreads like a breadth first search.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Params {
+    heuristic: Heuristic,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+enum Heuristic {
+    #[default]
+    Manhattan,
+    Euclidean,
+    Zero,
+}
+
+// Accepts heuristic=manhattan, heuristic=euclidean, or heuristic=zero.
+impl std::str::FromStr for Params {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+        for pair in params::param_pairs(s) {
+            match pair? {
+                ("heuristic", "manhattan") => params.heuristic = Heuristic::Manhattan,
+                ("heuristic", "euclidean") => params.heuristic = Heuristic::Euclidean,
+                ("heuristic", "zero") => params.heuristic = Heuristic::Zero,
+                ("heuristic", h) => {
+                    return Err(format!(
+                        "heuristic[{}] must be manhattan, euclidean, or zero",
+                        h
+                    ))
+                }
+                (key, _) => return Err(format!("param[{}] must be heuristic", key)),
+            }
+        }
+        Ok(params)
+    }
+}
+
+// Every searcher aims its estimate at the same finishes but keeps its own open and closed sets.
+#[derive(Clone)]
+struct Search {
+    heuristic: Heuristic,
+    targets: Vec<maze::Point>,
+}
+
+// Open entries order by the estimated total and then by the estimate alone so that ties favor the
+// square closer to a finish. That keeps the search narrow when a room offers many equal paths.
+type OpenEntry = Reverse<(u64, u64, i32, i32)>;
+
+impl Search {
+    fn estimate(&self, p: maze::Point) -> u64 {
+        self.targets
+            .iter()
+            .map(|t| {
+                let rows = (p.row - t.row).unsigned_abs() as u64;
+                let cols = (p.col - t.col).unsigned_abs() as u64;
+                match self.heuristic {
+                    Heuristic::Manhattan => (rows + cols) * SCALE,
+                    Heuristic::Euclidean => {
+                        (((rows * rows + cols * cols) as f64).sqrt() * SCALE as f64) as u64
+                    }
+                    Heuristic::Zero => 0,
+                }
+            })
+            .min()
+            .unwrap_or(0)
+    }
+
+    fn entry(&self, p: maze::Point, cost: u64) -> OpenEntry {
+        let h = self.estimate(p);
+        Reverse((cost + h, h, p.row, p.col))
+    }
+}
+
+// Squares waiting in the open set wear a dim shade of the thread color. Closing a square adds the
+// full color on top so the frontier stays darker than everything already explored.
+#[inline]
+fn open_paint(paint: solve::ThreadPaint) -> solve::ThreadPaint {
+    (paint >> 1) & OPEN_MASK
+}
+
+///
+/// Data only solvers------------------------------------------------------------------------------
+///
+pub fn hunt(monitor: monitor::MazeMonitor, params: &Params) {
+    let (all_start, finish) = if let Ok(mut lk) = monitor.lock() {
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let start = solve::pick_random_point(&lk.maze, &mut rng);
+        *lk.maze.get_mut(start.row, start.col) |= solve::START_BIT;
+        let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
+        *lk.maze.get_mut(finish.row, finish.col) |= solve::FINISH_BIT;
+        (start, finish)
+    } else {
+        print::maze_panic!("Thread panic.");
+    };
+    let search = Search {
+        heuristic: params.heuristic,
+        targets: vec![finish],
+    };
+
+    // One searcher keeps the dim open shade and the bright closed shade readable. Threads racing
+    // from the same start with the same estimate would only paint over each other.
+    hunter(
+        monitor.clone(),
+        solve::ThreadGuide {
+            index: 0,
+            paint: solve::THREAD_MASKS[0],
+            cache: solve::THREAD_CACHES[0],
+            start: all_start,
+            speed: 0,
+        },
+        search,
+    );
+
+    if let Ok(mut lk) = monitor.lock() {
+        for i in 0..lk.win_path.len() {
+            let p = lk.win_path[i];
+            *lk.maze.get_mut(p.0.row, p.0.col) &= !solve::THREAD_MASK;
+            *lk.maze.get_mut(p.0.row, p.0.col) |= p.1;
+        }
+        return;
+    }
+    print::maze_panic!("Thread panicked with the lock!");
+}
+
+pub fn corner(monitor: monitor::MazeMonitor, params: &Params) {
+    let (all_starts, finish) = if let Ok(mut lk) = monitor.lock() {
+        let mut all_starts = solve::set_corner_starts(&lk.maze);
+        for s in all_starts {
+            *lk.maze.get_mut(s.row, s.col) |= solve::START_BIT;
+        }
+        let finish = solve::center_finish(&lk.maze);
+        for p in maze::ALL_DIRECTIONS {
+            let next = maze::Point {
+                row: finish.row + p.row,
+                col: finish.col + p.col,
+            };
+            *lk.maze.get_mut(next.row, next.col) = solve::open_room_square(&lk.maze, next);
+        }
+        *lk.maze.get_mut(finish.row, finish.col) = (lk.maze.get(finish.row, finish.col)
+            & !maze::WALL_MASK)
+            | solve::FINISH_BIT
+            | maze::PATH_BIT;
+        all_starts.shuffle(&mut lk.maze.rng(maze::SOLVE_STREAM));
+        (all_starts, finish)
+    } else {
+        print::maze_panic!("Thread panick.");
+    };
+    let search = Search {
+        heuristic: params.heuristic,
+        targets: vec![finish],
+    };
+
+    let mut handles = Vec::with_capacity(solve::NUM_THREADS - 1);
+    for (i_thread, &mask) in solve::THREAD_MASKS.iter().skip(1).enumerate() {
+        let monitor_clone = monitor.clone();
+        let search_clone = search.clone();
+        handles.push(thread::spawn(move || {
+            hunter(
+                monitor_clone,
+                solve::ThreadGuide {
+                    index: i_thread + 1,
+                    paint: mask,
+                    cache: solve::THREAD_CACHES[i_thread + 1],
+                    start: all_starts[i_thread + 1],
+                    speed: 0,
+                },
+                search_clone,
+            );
+        }));
+    }
+
+    hunter(
+        monitor.clone(),
+        solve::ThreadGuide {
+            index: 0,
+            paint: solve::THREAD_MASKS[0],
+            cache: solve::THREAD_CACHES[0],
+            start: all_starts[0],
+            speed: 0,
+        },
+        search,
+    );
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    if let Ok(mut lk) = monitor.lock() {
+        for i in 0..lk.win_path.len() {
+            let p = lk.win_path[i];
+            *lk.maze.get_mut(p.0.row, p.0.col) &= !solve::THREAD_MASK;
+            *lk.maze.get_mut(p.0.row, p.0.col) |= p.1;
+        }
+        return;
+    }
+    print::maze_panic!("Thread panicked with the lock");
+}
+
+fn hunter(monitor: monitor::MazeMonitor, guide: solve::ThreadGuide, search: Search) {
+    let mut parents = HashMap::from([(guide.start, maze::Point { row: -1, col: -1 })]);
+    let mut costs = HashMap::from([(guide.start, 0)]);
+    let mut closed = HashSet::new();
+    let mut open = BinaryHeap::from([search.entry(guide.start, 0)]);
+    while let Some(Reverse((_, _, row, col))) = open.pop() {
+        let cur = maze::Point { row, col };
+        // A square can wait in the open set more than once if a cheaper way to it turns up.
+        if !closed.insert(cur) {
+            continue;
+        }
+        if let Ok(mut lk) = monitor.lock() {
+            if lk.win.is_some() {
+                return;
+            }
+            if (lk.maze.get(cur.row, cur.col) & solve::FINISH_BIT) != 0 {
+                *lk.maze.get_mut(cur.row, cur.col) |= guide.paint;
+                lk.win.get_or_insert(guide.index);
+                let mut prev = match parents.get(&cur) {
+                    Some(p) => p,
+                    None => print::maze_panic!("A* could not find parent."),
+                };
+                while prev.row > 0 {
+                    lk.win_path.push((*prev, guide.paint));
+                    prev = match parents.get(prev) {
+                        Some(parent) => parent,
+                        None => print::maze_panic!("A* could not find parent."),
+                    };
+                }
+                return;
+            }
+            *lk.maze.get_mut(cur.row, cur.col) |= guide.paint;
+        } else {
+            print::maze_panic!("Thread panicked!");
+        }
+
+        let cost = costs[&cur] + SCALE;
+        let mut i = guide.index;
+        for _ in 0..solve::NUM_DIRECTIONS {
+            let p = &maze::CARDINAL_DIRECTIONS[i];
+            let next = maze::Point {
+                row: cur.row + p.row,
+                col: cur.col + p.col,
+            };
+            let seen = costs.get(&next).copied();
+            if !closed.contains(&next)
+                && seen.is_none_or(|c| cost < c)
+                && match monitor.lock() {
+                    Err(p) => print::maze_panic!("Thread panicked: {}", p),
+                    Ok(mut lk) => {
+                        let square = lk.maze.get(next.row, next.col);
+                        if maze::is_path(square) && seen.is_none() && !solve::is_finish(square) {
+                            *lk.maze.get_mut(next.row, next.col) |= open_paint(guide.paint);
+                        }
+                        maze::is_path(square)
+                    }
+                }
+            {
+                costs.insert(next, cost);
+                parents.insert(next, cur);
+                open.push(search.entry(next, cost));
+            }
+            i = (i + 1) % solve::NUM_DIRECTIONS;
+        }
+    }
+}
+
+pub fn gather(monitor: monitor::MazeMonitor, params: &Params) {
+    let (all_start, finishes) = if let Ok(mut lk) = monitor.lock() {
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let start = solve::pick_random_point(&lk.maze, &mut rng);
+        *lk.maze.get_mut(start.row, start.col) |= solve::START_BIT;
+        let mut finishes = Vec::with_capacity(solve::NUM_GATHER_FINISHES);
+        for _ in 0..solve::NUM_GATHER_FINISHES {
+            let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
+            *lk.maze.get_mut(finish.row, finish.col) |= solve::FINISH_BIT;
+            finishes.push(finish);
+        }
+        (start, finishes)
+    } else {
+        print::maze_panic!("Thread panic.");
+    };
+    let search = Search {
+        heuristic: params.heuristic,
+        targets: finishes,
+    };
+
+    let mut handles = Vec::with_capacity(solve::NUM_THREADS - 1);
+    for (i_thread, &mask) in solve::THREAD_MASKS.iter().skip(1).enumerate() {
+        let monitor_clone = monitor.clone();
+        let search_clone = search.clone();
+        handles.push(thread::spawn(move || {
+            gatherer(
+                monitor_clone,
+                solve::ThreadGuide {
+                    index: i_thread + 1,
+                    paint: mask,
+                    cache: solve::THREAD_CACHES[i_thread + 1],
+                    start: all_start,
+                    speed: 0,
+                },
+                search_clone,
+            );
+        }));
+    }
+
+    gatherer(
+        monitor.clone(),
+        solve::ThreadGuide {
+            index: 0,
+            paint: solve::THREAD_MASKS[0],
+            cache: solve::THREAD_CACHES[0],
+            start: all_start,
+            speed: 0,
+        },
+        search,
+    );
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+fn gatherer(monitor: monitor::MazeMonitor, guide: solve::ThreadGuide, mut search: Search) {
+    let mut parents = HashMap::from([(guide.start, maze::Point { row: -1, col: -1 })]);
+    let mut costs = HashMap::from([(guide.start, 0)]);
+    let mut closed = HashSet::new();
+    let mut open = BinaryHeap::from([search.entry(guide.start, 0)]);
+    while let Some(Reverse((_, _, row, col))) = open.pop() {
+        let cur = maze::Point { row, col };
+        if !closed.insert(cur) {
+            continue;
+        }
+        if let Ok(mut lk) = monitor.lock() {
+            let square = lk.maze.get(cur.row, cur.col);
+            // We can only stop looking if we are the first to this finish. Keep looking otherwise.
+            match (solve::is_finish(square), solve::is_first(square)) {
+                (true, true) => {
+                    *lk.maze.get_mut(cur.row, cur.col) |= guide.paint | guide.cache;
+                    return;
+                }
+                (true, false) => {
+                    *lk.maze.get_mut(cur.row, cur.col) |= guide.cache;
+                    // A claimed finish should no longer pull the search toward it.
+                    search.targets.retain(|&t| t != cur);
+                }
+                (_, _) => {
+                    *lk.maze.get_mut(cur.row, cur.col) |= guide.cache | guide.paint;
+                }
+            }
+        } else {
+            print::maze_panic!("Thread panicked!");
+        }
+
+        let cost = costs[&cur] + SCALE;
+        let mut i = guide.index;
+        for _ in 0..solve::NUM_DIRECTIONS {
+            let p = &maze::CARDINAL_DIRECTIONS[i];
+            let next = maze::Point {
+                row: cur.row + p.row,
+                col: cur.col + p.col,
+            };
+            let seen = costs.get(&next).copied();
+            if !closed.contains(&next)
+                && seen.is_none_or(|c| cost < c)
+                && match monitor.lock() {
+                    Err(p) => print::maze_panic!("Thread panicked: {}", p),
+                    Ok(mut lk) => {
+                        let square = lk.maze.get(next.row, next.col);
+                        if maze::is_path(square) && seen.is_none() && !solve::is_finish(square) {
+                            *lk.maze.get_mut(next.row, next.col) |= open_paint(guide.paint);
+                        }
+                        maze::is_path(square)
+                    }
+                }
+            {
+                costs.insert(next, cost);
+                parents.insert(next, cur);
+                open.push(search.entry(next, cost));
+            }
+            i = (i + 1) % solve::NUM_DIRECTIONS;
+        }
+    }
+}
+
+///
+/// History based solvers for recording and playback-----------------------------------------------
+///
+pub fn hunt_history(monitor: monitor::MazeMonitor, params: &Params) {
+    let (all_start, finish) = if let Ok(mut lk) = monitor.lock() {
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let start = solve::pick_random_point(&lk.maze, &mut rng);
+        let start_square = lk.maze.get(start.row, start.col);
+        lk.maze.solve_history.push(maze::Delta {
+            id: start,
+            before: start_square,
+            after: start_square | solve::START_BIT,
+            burst: 1,
+        });
+        *lk.maze.get_mut(start.row, start.col) |= solve::START_BIT;
+        let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
+        let finish_square = lk.maze.get(finish.row, finish.col);
+        lk.maze.solve_history.push(maze::Delta {
+            id: finish,
+            before: finish_square,
+            after: finish_square | solve::FINISH_BIT,
+            burst: 1,
+        });
+        *lk.maze.get_mut(finish.row, finish.col) |= solve::FINISH_BIT;
+        (start, finish)
+    } else {
+        print::maze_panic!("Thread panic.");
+    };
+    let search = Search {
+        heuristic: params.heuristic,
+        targets: vec![finish],
+    };
+
+    // Alone on the maze, the searcher shows one square per frame.
+    hunter_history(
+        monitor.clone(),
+        solve::ThreadGuide {
+            index: 0,
+            paint: solve::THREAD_MASKS[0],
+            cache: solve::THREAD_CACHES[0],
+            start: all_start,
+            speed: 0,
+        },
+        search,
+        1,
+    );
+
+    if let Ok(mut lk) = monitor.lock() {
+        for i in 0..lk.win_path.len() {
+            let p = lk.win_path[i];
+            let square = lk.maze.get(p.0.row, p.0.col);
+            lk.maze.solve_history.push(maze::Delta {
+                id: p.0,
+                before: square,
+                after: (square & !solve::THREAD_MASK) | p.1,
+                burst: 1,
+            });
+            *lk.maze.get_mut(p.0.row, p.0.col) = (square & !solve::THREAD_MASK) | p.1;
+        }
+        return;
+    }
+    print::maze_panic!("Thread panicked with the lock!");
+}
+
+pub fn corner_history(monitor: monitor::MazeMonitor, params: &Params) {
+    let (all_starts, finish) = if let Ok(mut lk) = monitor.lock() {
+        let mut all_starts = solve::set_corner_starts(&lk.maze);
+        for s in all_starts {
+            let start_square = lk.maze.get(s.row, s.col);
+            lk.maze.solve_history.push(maze::Delta {
+                id: s,
+                before: start_square,
+                after: start_square | solve::START_BIT,
+                burst: BURST,
+            });
+            *lk.maze.get_mut(s.row, s.col) |= solve::START_BIT;
+        }
+        let finish = solve::center_finish(&lk.maze);
+        for p in maze::ALL_DIRECTIONS {
+            let next = maze::Point {
+                row: finish.row + p.row,
+                col: finish.col + p.col,
+            };
+            let next_square = lk.maze.get(next.row, next.col);
+            let opened = solve::open_room_square(&lk.maze, next);
+            lk.maze.solve_history.push(maze::Delta {
+                id: next,
+                before: next_square,
+                after: opened,
+                burst: BURST,
+            });
+            *lk.maze.get_mut(next.row, next.col) = opened;
+        }
+        let finish_square = lk.maze.get(finish.row, finish.col);
+        lk.maze.solve_history.push(maze::Delta {
+            id: finish,
+            before: finish_square,
+            after: (finish_square & !maze::WALL_MASK) | solve::FINISH_BIT | maze::PATH_BIT,
+            burst: BURST,
+        });
+        *lk.maze.get_mut(finish.row, finish.col) =
+            (finish_square & !maze::WALL_MASK) | solve::FINISH_BIT | maze::PATH_BIT;
+        all_starts.shuffle(&mut lk.maze.rng(maze::SOLVE_STREAM));
+        (all_starts, finish)
+    } else {
+        print::maze_panic!("Thread panick.");
+    };
+    let search = Search {
+        heuristic: params.heuristic,
+        targets: vec![finish],
+    };
+
+    let mut handles = Vec::with_capacity(solve::NUM_THREADS - 1);
+    for (i_thread, &mask) in solve::THREAD_MASKS.iter().skip(1).enumerate() {
+        let monitor_clone = monitor.clone();
+        let search_clone = search.clone();
+        handles.push(thread::spawn(move || {
+            hunter_history(
+                monitor_clone,
+                solve::ThreadGuide {
+                    index: i_thread + 1,
+                    paint: mask,
+                    cache: solve::THREAD_CACHES[i_thread + 1],
+                    start: all_starts[i_thread + 1],
+                    speed: 0,
+                },
+                search_clone,
+                BURST,
+            );
+        }));
+    }
+
+    hunter_history(
+        monitor.clone(),
+        solve::ThreadGuide {
+            index: 0,
+            paint: solve::THREAD_MASKS[0],
+            cache: solve::THREAD_CACHES[0],
+            start: all_starts[0],
+            speed: 0,
+        },
+        search,
+        BURST,
+    );
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    if let Ok(mut lk) = monitor.lock() {
+        // I kind of cheated by having every history claim it was a 4-burst. That works but we need
+        // to tidy up so when we start reversing from the end the jumps by 4-bursts are correct.
+        let len = lk.maze.solve_history.len();
+        if len % BURST != 0 {
+            lk.maze
+                .solve_history
+                .slice_mut(len - (len % BURST), len)
+                .iter_mut()
+                .for_each(|s| s.burst = 1);
+        }
+        for i in 0..lk.win_path.len() {
+            let p = lk.win_path[i];
+            let square = lk.maze.get(p.0.row, p.0.col);
+            lk.maze.solve_history.push(maze::Delta {
+                id: p.0,
+                before: square,
+                after: (square & !solve::THREAD_MASK) | p.1,
+                burst: 1,
+            });
+            *lk.maze.get_mut(p.0.row, p.0.col) = (square & !solve::THREAD_MASK) | p.1;
+        }
+        return;
+    }
+    print::maze_panic!("Thread panicked with the lock");
+}
+
+fn hunter_history(
+    monitor: monitor::MazeMonitor,
+    guide: solve::ThreadGuide,
+    search: Search,
+    burst: usize,
+) {
+    let mut parents = HashMap::from([(guide.start, maze::Point { row: -1, col: -1 })]);
+    let mut costs = HashMap::from([(guide.start, 0)]);
+    let mut closed = HashSet::new();
+    let mut open = BinaryHeap::from([search.entry(guide.start, 0)]);
+    while let Some(Reverse((_, _, row, col))) = open.pop() {
+        let cur = maze::Point { row, col };
+        if !closed.insert(cur) {
+            continue;
+        }
+        if let Ok(mut lk) = monitor.lock() {
+            if lk.win.is_some() {
+                return;
+            }
+            let square = lk.maze.get(cur.row, cur.col);
+            lk.maze.solve_history.push(maze::Delta {
+                id: cur,
+                before: square,
+                after: square | guide.paint,
+                burst,
+            });
+            *lk.maze.get_mut(cur.row, cur.col) |= guide.paint;
+            if solve::is_finish(square) {
+                lk.win.get_or_insert(guide.index);
+                let mut prev = match parents.get(&cur) {
+                    Some(p) => p,
+                    None => print::maze_panic!("A* could not find parent."),
+                };
+                while prev.row > 0 {
+                    lk.win_path.push((*prev, guide.paint));
+                    prev = match parents.get(prev) {
+                        Some(parent) => parent,
+                        None => print::maze_panic!("A* could not find parent."),
+                    };
+                }
+                return;
+            }
+        } else {
+            print::maze_panic!("Thread panicked!");
+        }
+
+        let cost = costs[&cur] + SCALE;
+        let mut i = guide.index;
+        for _ in 0..solve::NUM_DIRECTIONS {
+            let p = &maze::CARDINAL_DIRECTIONS[i];
+            let next = maze::Point {
+                row: cur.row + p.row,
+                col: cur.col + p.col,
+            };
+            let seen = costs.get(&next).copied();
+            if !closed.contains(&next)
+                && seen.is_none_or(|c| cost < c)
+                && match monitor.lock() {
+                    Err(p) => print::maze_panic!("Thread panicked: {}", p),
+                    Ok(mut lk) => open_history(&mut lk, next, seen.is_none(), guide.paint, burst),
+                }
+            {
+                costs.insert(next, cost);
+                parents.insert(next, cur);
+                open.push(search.entry(next, cost));
+            }
+            i = (i + 1) % solve::NUM_DIRECTIONS;
+        }
+    }
+}
+
+pub fn gather_history(monitor: monitor::MazeMonitor, params: &Params) {
+    let (all_start, finishes) = if let Ok(mut lk) = monitor.lock() {
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let start = solve::pick_random_point(&lk.maze, &mut rng);
+        let start_square = lk.maze.get(start.row, start.col);
+        lk.maze.solve_history.push(maze::Delta {
+            id: start,
+            before: start_square,
+            after: start_square | solve::START_BIT,
+            burst: BURST,
+        });
+        *lk.maze.get_mut(start.row, start.col) |= solve::START_BIT;
+        let mut finishes = Vec::with_capacity(solve::NUM_GATHER_FINISHES);
+        for _ in 0..solve::NUM_GATHER_FINISHES {
+            let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
+            let finish_square = lk.maze.get(finish.row, finish.col);
+            lk.maze.solve_history.push(maze::Delta {
+                id: finish,
+                before: finish_square,
+                after: finish_square | solve::FINISH_BIT,
+                burst: BURST,
+            });
+            *lk.maze.get_mut(finish.row, finish.col) |= solve::FINISH_BIT;
+            finishes.push(finish);
+        }
+        (start, finishes)
+    } else {
+        print::maze_panic!("Thread panick.");
+    };
+    let search = Search {
+        heuristic: params.heuristic,
+        targets: finishes,
+    };
+
+    let mut handles = Vec::with_capacity(solve::NUM_THREADS - 1);
+    for (i_thread, &mask) in solve::THREAD_MASKS.iter().skip(1).enumerate() {
+        let monitor_clone = monitor.clone();
+        let search_clone = search.clone();
+        handles.push(thread::spawn(move || {
+            gatherer_history(
+                monitor_clone,
+                solve::ThreadGuide {
+                    index: i_thread + 1,
+                    paint: mask,
+                    cache: solve::THREAD_CACHES[i_thread + 1],
+                    start: all_start,
+                    speed: 0,
+                },
+                search_clone,
+            );
+        }));
+    }
+    gatherer_history(
+        monitor.clone(),
+        solve::ThreadGuide {
+            index: 0,
+            paint: solve::THREAD_MASKS[0],
+            cache: solve::THREAD_CACHES[0],
+            start: all_start,
+            speed: 0,
+        },
+        search,
+    );
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    if let Ok(mut lk) = monitor.lock() {
+        let len = lk.maze.solve_history.len();
+        if len % BURST != 0 {
+            lk.maze
+                .solve_history
+                .slice_mut(len - (len % BURST), len)
+                .iter_mut()
+                .for_each(|s| s.burst = 1);
+        }
+        return;
+    }
+    print::maze_panic!("thread panick.");
+}
+
+fn gatherer_history(monitor: monitor::MazeMonitor, guide: solve::ThreadGuide, mut search: Search) {
+    let mut parents = HashMap::from([(guide.start, maze::Point { row: -1, col: -1 })]);
+    let mut costs = HashMap::from([(guide.start, 0)]);
+    let mut closed = HashSet::new();
+    let mut open = BinaryHeap::from([search.entry(guide.start, 0)]);
+    while let Some(Reverse((_, _, row, col))) = open.pop() {
+        let cur = maze::Point { row, col };
+        if !closed.insert(cur) {
+            continue;
+        }
+        if let Ok(mut lk) = monitor.lock() {
+            let before = lk.maze.get(cur.row, cur.col);
+            // We can only stop looking if we are the first to this finish. Keep looking otherwise.
+            match (solve::is_finish(before), solve::is_first(before)) {
+                (true, true) => {
+                    lk.maze.solve_history.push(maze::Delta {
+                        id: cur,
+                        before,
+                        after: before | guide.paint | guide.cache,
+                        burst: BURST,
+                    });
+                    *lk.maze.get_mut(cur.row, cur.col) |= guide.paint | guide.cache;
+                    return;
+                }
+                (true, false) => {
+                    lk.maze.solve_history.push(maze::Delta {
+                        id: cur,
+                        before,
+                        after: before | guide.cache,
+                        burst: BURST,
+                    });
+                    *lk.maze.get_mut(cur.row, cur.col) |= guide.cache;
+                    search.targets.retain(|&t| t != cur);
+                }
+                _ => {
+                    lk.maze.solve_history.push(maze::Delta {
+                        id: cur,
+                        before,
+                        after: before | guide.cache | guide.paint,
+                        burst: BURST,
+                    });
+                    *lk.maze.get_mut(cur.row, cur.col) |= guide.cache | guide.paint;
+                }
+            }
+        } else {
+            print::maze_panic!("Thread panicked!");
+        }
+
+        let cost = costs[&cur] + SCALE;
+        let mut i = guide.index;
+        for _ in 0..solve::NUM_DIRECTIONS {
+            let p = &maze::CARDINAL_DIRECTIONS[i];
+            let next = maze::Point {
+                row: cur.row + p.row,
+                col: cur.col + p.col,
+            };
+            let seen = costs.get(&next).copied();
+            if !closed.contains(&next)
+                && seen.is_none_or(|c| cost < c)
+                && match monitor.lock() {
+                    Err(p) => print::maze_panic!("Thread panicked: {}", p),
+                    Ok(mut lk) => open_history(&mut lk, next, seen.is_none(), guide.paint, BURST),
+                }
+            {
+                costs.insert(next, cost);
+                parents.insert(next, cur);
+                open.push(search.entry(next, cost));
+            }
+            i = (i + 1) % solve::NUM_DIRECTIONS;
+        }
+    }
+}
+
+// Shades a path square the first time a thread adds it to its open set. Finishes keep their own
+// marking until a thread actually reaches them.
+fn open_history(
+    lk: &mut monitor::Monitor,
+    next: maze::Point,
+    first_seen: bool,
+    paint: solve::ThreadPaint,
+    burst: usize,
+) -> bool {
+    let square = lk.maze.get(next.row, next.col);
+    if !maze::is_path(square) {
+        return false;
+    }
+    if first_seen && !solve::is_finish(square) {
+        lk.maze.solve_history.push(maze::Delta {
+            id: next,
+            before: square,
+            after: square | open_paint(paint),
+            burst,
+        });
+        *lk.maze.get_mut(next.row, next.col) |= open_paint(paint);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn params_pick_a_heuristic() {
+        let params: Params = "heuristic=euclidean".parse().unwrap();
+        assert_eq!(params.heuristic, Heuristic::Euclidean);
+        assert!("heuristic=chebyshev".parse::<Params>().is_err());
+        assert!("weight=2".parse::<Params>().is_err());
+    }
+}
@@ -13,9 +13,10 @@ const BURST: usize = 4;
 ///
 pub fn hunt(monitor: monitor::MazeMonitor) {
     let all_start: maze::Point = if let Ok(mut lk) = monitor.lock() {
-        let start = solve::pick_random_point(&lk.maze);
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let start = solve::pick_random_point(&lk.maze, &mut rng);
         *lk.maze.get_mut(start.row, start.col) |= solve::START_BIT;
-        let finish: maze::Point = solve::pick_random_point(&lk.maze);
+        let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
         *lk.maze.get_mut(finish.row, finish.col) |= solve::FINISH_BIT;
         start
     } else {
@@ -65,33 +66,29 @@ pub fn hunt(monitor: monitor::MazeMonitor) {
 }
 
 pub fn corner(monitor: monitor::MazeMonitor) {
-    let mut all_starts: [maze::Point; 4] = if let Ok(mut lk) = monitor.lock() {
-        let all_starts = solve::set_corner_starts(&lk.maze);
+    let all_starts: [maze::Point; 4] = if let Ok(mut lk) = monitor.lock() {
+        let mut all_starts = solve::set_corner_starts(&lk.maze);
         for s in all_starts {
             *lk.maze.get_mut(s.row, s.col) |= solve::START_BIT;
         }
-        let finish = maze::Point {
-            row: lk.maze.rows() / 2,
-            col: lk.maze.cols() / 2,
-        };
+        let finish = solve::center_finish(&lk.maze);
         for p in maze::ALL_DIRECTIONS {
             let next = maze::Point {
                 row: finish.row + p.row,
                 col: finish.col + p.col,
             };
-            *lk.maze.get_mut(next.row, next.col) =
-                (lk.maze.get(next.row, next.col) & !maze::WALL_MASK) | maze::PATH_BIT;
+            *lk.maze.get_mut(next.row, next.col) = solve::open_room_square(&lk.maze, next);
         }
         *lk.maze.get_mut(finish.row, finish.col) = (lk.maze.get(finish.row, finish.col)
             & !maze::WALL_MASK)
             | solve::FINISH_BIT
             | maze::PATH_BIT;
+        all_starts.shuffle(&mut lk.maze.rng(maze::SOLVE_STREAM));
         all_starts
     } else {
         print::maze_panic!("Thread panick.");
     };
 
-    all_starts.shuffle(&mut thread_rng());
     let mut handles = Vec::with_capacity(solve::NUM_THREADS - 1);
     for (i_thread, &mask) in solve::THREAD_MASKS.iter().skip(1).enumerate() {
         let monitor_clone = monitor.clone();
@@ -186,10 +183,11 @@ fn hunter(monitor: monitor::MazeMonitor, guide: solve::ThreadGuide) {
 
 pub fn gather(monitor: monitor::MazeMonitor) {
     let all_start: maze::Point = if let Ok(mut lk) = monitor.lock() {
-        let start = solve::pick_random_point(&lk.maze);
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let start = solve::pick_random_point(&lk.maze, &mut rng);
         *lk.maze.get_mut(start.row, start.col) |= solve::START_BIT;
         for _ in 0..solve::NUM_GATHER_FINISHES {
-            let finish: maze::Point = solve::pick_random_point(&lk.maze);
+            let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
             *lk.maze.get_mut(finish.row, finish.col) |= solve::FINISH_BIT;
         }
         start
@@ -278,7 +276,8 @@ fn gatherer(monitor: monitor::MazeMonitor, guide: solve::ThreadGuide) {
 ///
 pub fn hunt_history(monitor: monitor::MazeMonitor) {
     let all_start: maze::Point = if let Ok(mut lk) = monitor.lock() {
-        let start = solve::pick_random_point(&lk.maze);
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let start = solve::pick_random_point(&lk.maze, &mut rng);
         let start_square = lk.maze.get(start.row, start.col);
         lk.maze.solve_history.push(maze::Delta {
             id: start,
@@ -287,7 +286,7 @@ pub fn hunt_history(monitor: monitor::MazeMonitor) {
             burst: BURST,
         });
         *lk.maze.get_mut(start.row, start.col) |= solve::START_BIT;
-        let finish: maze::Point = solve::pick_random_point(&lk.maze);
+        let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
         let finish_square = lk.maze.get(finish.row, finish.col);
         lk.maze.solve_history.push(maze::Delta {
             id: finish,
@@ -359,8 +358,8 @@ pub fn hunt_history(monitor: monitor::MazeMonitor) {
 }
 
 pub fn corner_history(monitor: monitor::MazeMonitor) {
-    let mut all_starts: [maze::Point; 4] = if let Ok(mut lk) = monitor.lock() {
-        let all_starts = solve::set_corner_starts(&lk.maze);
+    let all_starts: [maze::Point; 4] = if let Ok(mut lk) = monitor.lock() {
+        let mut all_starts = solve::set_corner_starts(&lk.maze);
         for s in all_starts {
             let start_square = lk.maze.get(s.row, s.col);
             lk.maze.solve_history.push(maze::Delta {
@@ -371,24 +370,21 @@ pub fn corner_history(monitor: monitor::MazeMonitor) {
             });
             *lk.maze.get_mut(s.row, s.col) |= solve::START_BIT;
         }
-        let finish = maze::Point {
-            row: lk.maze.rows() / 2,
-            col: lk.maze.cols() / 2,
-        };
+        let finish = solve::center_finish(&lk.maze);
         for p in maze::ALL_DIRECTIONS {
             let next = maze::Point {
                 row: finish.row + p.row,
                 col: finish.col + p.col,
             };
             let next_square = lk.maze.get(next.row, next.col);
+            let opened = solve::open_room_square(&lk.maze, next);
             lk.maze.solve_history.push(maze::Delta {
                 id: next,
                 before: next_square,
-                after: (next_square & !maze::WALL_MASK) | maze::PATH_BIT,
+                after: opened,
                 burst: BURST,
             });
-            *lk.maze.get_mut(next.row, next.col) =
-                (next_square & !maze::WALL_MASK) | maze::PATH_BIT;
+            *lk.maze.get_mut(next.row, next.col) = opened;
         }
         let finish_square = lk.maze.get(finish.row, finish.col);
         lk.maze.solve_history.push(maze::Delta {
@@ -399,12 +395,12 @@ pub fn corner_history(monitor: monitor::MazeMonitor) {
         });
         *lk.maze.get_mut(finish.row, finish.col) =
             (finish_square & !maze::WALL_MASK) | solve::FINISH_BIT | maze::PATH_BIT;
+        all_starts.shuffle(&mut lk.maze.rng(maze::SOLVE_STREAM));
         all_starts
     } else {
         print::maze_panic!("Thread panick.");
     };
 
-    all_starts.shuffle(&mut thread_rng());
     let mut handles = Vec::with_capacity(solve::NUM_THREADS - 1);
     for (i_thread, &mask) in solve::THREAD_MASKS.iter().skip(1).enumerate() {
         let monitor_clone = monitor.clone();
@@ -526,7 +522,8 @@ fn hunter_history(monitor: monitor::MazeMonitor, guide: solve::ThreadGuide) {
 
 pub fn gather_history(monitor: monitor::MazeMonitor) {
     let all_start: maze::Point = if let Ok(mut lk) = monitor.lock() {
-        let start = solve::pick_random_point(&lk.maze);
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let start = solve::pick_random_point(&lk.maze, &mut rng);
         let start_square = lk.maze.get(start.row, start.col);
         lk.maze.solve_history.push(maze::Delta {
             id: start,
@@ -536,7 +533,7 @@ pub fn gather_history(monitor: monitor::MazeMonitor) {
         });
         *lk.maze.get_mut(start.row, start.col) |= solve::START_BIT;
         for _ in 0..solve::NUM_GATHER_FINISHES {
-            let finish: maze::Point = solve::pick_random_point(&lk.maze);
+            let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
             let finish_square = lk.maze.get(finish.row, finish.col);
             lk.maze.solve_history.push(maze::Delta {
                 id: finish,
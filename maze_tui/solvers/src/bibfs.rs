@@ -0,0 +1,227 @@
+use crate::solve;
+use maze;
+use print;
+
+use std::collections::{HashMap, VecDeque};
+use std::thread;
+
+const BURST: usize = 4;
+
+// The two sides keep their own thread colors. Where they meet is drawn white and the path they
+// join is drawn in a third thread color so it stands out from both searches.
+pub const MEET_PAINT: solve::ThreadPaint = 0xFFFFFF;
+const PATH_PAINT: solve::ThreadPaint = solve::THREAD_MASKS[2];
+const START_SIDE: usize = 0;
+const FINISH_SIDE: usize = 1;
+
+type Placement = fn(&mut maze::Maze, bool) -> (maze::Point, Vec<maze::Point>);
+
+// One side of the search. It grows from its roots and stops when it steps onto a square that the
+// other side has already seen or onto one of the other side's roots.
+struct Side {
+    index: usize,
+    paint: solve::ThreadPaint,
+    cache: solve::ThreadCache,
+    meets: maze::Square,
+    parents: HashMap<maze::Point, maze::Point>,
+    bfs: VecDeque<maze::Point>,
+}
+
+///
+/// Data only solvers------------------------------------------------------------------------------
+///
+pub fn hunt(monitor: monitor::MazeMonitor) {
+    search(monitor, place_hunt, false);
+}
+
+pub fn gather(monitor: monitor::MazeMonitor) {
+    search(monitor, place_gather, false);
+}
+
+///
+/// History based solvers for recording and playback-----------------------------------------------
+///
+pub fn hunt_history(monitor: monitor::MazeMonitor) {
+    search(monitor, place_hunt, true);
+}
+
+pub fn gather_history(monitor: monitor::MazeMonitor) {
+    search(monitor, place_gather, true);
+}
+
+// Each side gets a thread of its own like the other bfs solvers. A lock handed between two threads
+// tends to go back to whichever thread just released it, so each side gives up its turn after every
+// square to keep the two growing at about the same pace. In gather mode the finish side grows from
+// every finish at once so it meets the start side at the closest one.
+fn search(monitor: monitor::MazeMonitor, place: Placement, record: bool) {
+    let (start, finishes, steps) = match monitor.lock() {
+        Ok(mut lk) => {
+            let (start, finishes) = place(&mut lk.maze, record);
+            (start, finishes, lk.maze.solve_history.len())
+        }
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    let monitor_clone = monitor.clone();
+    let handle = thread::spawn(move || {
+        let mut side = Side::new(FINISH_SIDE, START_SIDE, solve::START_BIT, &finishes);
+        let meet = side.grow(&monitor_clone, record);
+        (side, meet)
+    });
+    let mut start_side = Side::new(START_SIDE, FINISH_SIDE, solve::FINISH_BIT, &[start]);
+    let start_meet = start_side.grow(&monitor, record);
+    let (finish_side, finish_meet) = match handle.join() {
+        Ok(side) => side,
+        Err(_) => print::maze_panic!("finish side panicked"),
+    };
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("thread panicked with the lock"),
+    };
+    if record {
+        // Every search step claimed to be a 4-burst so the tail is tidied up for reverse playback.
+        // The placement squares before the first step are single frames of their own.
+        let len = lk.maze.solve_history.len();
+        let tail = (len - steps) % BURST;
+        if tail != 0 {
+            lk.maze
+                .solve_history
+                .slice_mut(len - tail, len)
+                .iter_mut()
+                .for_each(|s| s.burst = 1);
+        }
+    }
+    // A side that ran out of squares without meeting the other means there is no path at all.
+    let meet = match start_meet.or(finish_meet) {
+        Some(meet) => meet,
+        None => return,
+    };
+    let mut path = chain(&start_side.parents, meet);
+    path.reverse();
+    path.extend(chain(&finish_side.parents, meet).into_iter().skip(1));
+    for p in path {
+        let square = lk.maze.get(p.row, p.col);
+        let paint = if p == meet { MEET_PAINT } else { PATH_PAINT };
+        set_square(
+            &mut lk.maze,
+            p,
+            (square & !solve::THREAD_MASK) | paint,
+            1,
+            record,
+        );
+    }
+}
+
+impl Side {
+    fn new(index: usize, other: usize, other_root: maze::Square, roots: &[maze::Point]) -> Self {
+        let none = maze::Point { row: -1, col: -1 };
+        Self {
+            index,
+            paint: solve::THREAD_MASKS[index],
+            cache: solve::THREAD_CACHES[index],
+            meets: solve::THREAD_CACHES[other] | other_root,
+            parents: roots.iter().map(|&r| (r, none)).collect(),
+            bfs: roots.iter().copied().collect(),
+        }
+    }
+
+    // Returns the square where this side met the other or nothing if the other side met it first
+    // or this side ran out of squares.
+    fn grow(&mut self, monitor: &monitor::MazeMonitor, record: bool) -> Option<maze::Point> {
+        while let Some(cur) = self.bfs.pop_front() {
+            let mut lk = match monitor.lock() {
+                Ok(l) => l,
+                Err(_) => print::maze_panic!("thread panicked with the lock"),
+            };
+            if lk.win.is_some() {
+                return None;
+            }
+            let square = lk.maze.get(cur.row, cur.col);
+            if (square & self.meets) != 0 {
+                lk.win.get_or_insert(self.index);
+                return Some(cur);
+            }
+            set_square(
+                &mut lk.maze,
+                cur,
+                square | self.paint | self.cache,
+                BURST,
+                record,
+            );
+            for p in maze::CARDINAL_DIRECTIONS {
+                let next = maze::Point {
+                    row: cur.row + p.row,
+                    col: cur.col + p.col,
+                };
+                if lk.maze.is_open(next.row, next.col) && !self.parents.contains_key(&next) {
+                    self.parents.insert(next, cur);
+                    self.bfs.push_back(next);
+                }
+            }
+            drop(lk);
+            thread::yield_now();
+        }
+        None
+    }
+}
+
+// The squares from the meeting point back to the root that side grew from.
+fn chain(parents: &HashMap<maze::Point, maze::Point>, meet: maze::Point) -> Vec<maze::Point> {
+    let mut squares = vec![meet];
+    let mut cur = meet;
+    while let Some(&prev) = parents.get(&cur) {
+        if prev.row < 0 {
+            break;
+        }
+        squares.push(prev);
+        cur = prev;
+    }
+    squares
+}
+
+///
+/// Placement matches the bfs solvers so the visited squares of both can be compared on one seed.
+///
+fn set_square(
+    maze: &mut maze::Maze,
+    p: maze::Point,
+    after: maze::Square,
+    burst: usize,
+    record: bool,
+) {
+    if record {
+        let before = maze.get(p.row, p.col);
+        maze.solve_history.push(maze::Delta {
+            id: p,
+            before,
+            after,
+            burst,
+        });
+    }
+    *maze.get_mut(p.row, p.col) = after;
+}
+
+fn place_hunt(maze: &mut maze::Maze, record: bool) -> (maze::Point, Vec<maze::Point>) {
+    let mut rng = maze.rng(maze::SOLVE_STREAM);
+    let start = solve::pick_random_point(maze, &mut rng);
+    let square = maze.get(start.row, start.col);
+    set_square(maze, start, square | solve::START_BIT, 1, record);
+    let finish = solve::pick_random_point(maze, &mut rng);
+    let square = maze.get(finish.row, finish.col);
+    set_square(maze, finish, square | solve::FINISH_BIT, 1, record);
+    (start, vec![finish])
+}
+
+fn place_gather(maze: &mut maze::Maze, record: bool) -> (maze::Point, Vec<maze::Point>) {
+    let mut rng = maze.rng(maze::SOLVE_STREAM);
+    let start = solve::pick_random_point(maze, &mut rng);
+    let square = maze.get(start.row, start.col);
+    set_square(maze, start, square | solve::START_BIT, 1, record);
+    let mut finishes = Vec::with_capacity(solve::NUM_GATHER_FINISHES);
+    for _ in 0..solve::NUM_GATHER_FINISHES {
+        let finish = solve::pick_random_point(maze, &mut rng);
+        let square = maze.get(finish.row, finish.col);
+        set_square(maze, finish, square | solve::FINISH_BIT, 1, record);
+        finishes.push(finish);
+    }
+    (start, finishes)
+}
@@ -0,0 +1,275 @@
+use crate::solve;
+use maze;
+use print;
+
+use std::collections::VecDeque;
+
+// Filled corridors turn a dark gray so the corridors still open stand out around them. The squares
+// left on a shortest path between a start and a finish are drawn in the first thread color.
+pub const FILL_PAINT: solve::ThreadPaint = 0x303030;
+const PATH_PAINT: solve::ThreadPaint = solve::THREAD_MASKS[0];
+const UNSEEN: u32 = u32::MAX;
+
+type Placement = fn(&mut maze::Maze, bool);
+
+///
+/// Data only solvers------------------------------------------------------------------------------
+///
+pub fn hunt(monitor: monitor::MazeMonitor) {
+    fill(monitor, place_hunt, false);
+}
+
+pub fn gather(monitor: monitor::MazeMonitor) {
+    fill(monitor, place_gather, false);
+}
+
+pub fn corner(monitor: monitor::MazeMonitor) {
+    fill(monitor, place_corner, false);
+}
+
+///
+/// History based solvers for recording and playback-----------------------------------------------
+///
+pub fn hunt_history(monitor: monitor::MazeMonitor) {
+    fill(monitor, place_hunt, true);
+}
+
+pub fn gather_history(monitor: monitor::MazeMonitor) {
+    fill(monitor, place_gather, true);
+}
+
+pub fn corner_history(monitor: monitor::MazeMonitor) {
+    fill(monitor, place_corner, true);
+}
+
+// Filling needs no threads. It plans every corridor first and then paints them one burst each so
+// a corridor disappears in a single frame of playback.
+fn fill(monitor: monitor::MazeMonitor, place: Placement, record: bool) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    place(&mut lk.maze, record);
+    let (corridors, filled) = plan_fills(&lk.maze);
+    for corridor in corridors {
+        let deltas: Vec<maze::Delta> = corridor
+            .iter()
+            .map(|&p| {
+                let before = lk.maze.get(p.row, p.col);
+                maze::Delta {
+                    id: p,
+                    before,
+                    after: before | FILL_PAINT,
+                    burst: corridor.len(),
+                }
+            })
+            .collect();
+        for d in &deltas {
+            *lk.maze.get_mut(d.id.row, d.id.col) = d.after;
+        }
+        if record {
+            lk.maze.solve_history.push_burst(&deltas);
+        }
+    }
+    for p in shortest_squares(&lk.maze, &filled) {
+        let before = lk.maze.get(p.row, p.col);
+        set_square(&mut lk.maze, p, before | PATH_PAINT, record);
+    }
+    lk.win.get_or_insert(0);
+}
+
+///
+/// Placement matches the other solvers so a fill can be compared with their searches.
+///
+fn set_square(maze: &mut maze::Maze, p: maze::Point, after: maze::Square, record: bool) {
+    if record {
+        let before = maze.get(p.row, p.col);
+        maze.solve_history.push(maze::Delta {
+            id: p,
+            before,
+            after,
+            burst: 1,
+        });
+    }
+    *maze.get_mut(p.row, p.col) = after;
+}
+
+fn place_hunt(maze: &mut maze::Maze, record: bool) {
+    let mut rng = maze.rng(maze::SOLVE_STREAM);
+    let start = solve::pick_random_point(maze, &mut rng);
+    let square = maze.get(start.row, start.col);
+    set_square(maze, start, square | solve::START_BIT, record);
+    let finish = solve::pick_random_point(maze, &mut rng);
+    let square = maze.get(finish.row, finish.col);
+    set_square(maze, finish, square | solve::FINISH_BIT, record);
+}
+
+fn place_gather(maze: &mut maze::Maze, record: bool) {
+    let mut rng = maze.rng(maze::SOLVE_STREAM);
+    let start = solve::pick_random_point(maze, &mut rng);
+    let square = maze.get(start.row, start.col);
+    set_square(maze, start, square | solve::START_BIT, record);
+    for _ in 0..solve::NUM_GATHER_FINISHES {
+        let finish = solve::pick_random_point(maze, &mut rng);
+        let square = maze.get(finish.row, finish.col);
+        set_square(maze, finish, square | solve::FINISH_BIT, record);
+    }
+}
+
+fn place_corner(maze: &mut maze::Maze, record: bool) {
+    for s in solve::set_corner_starts(maze) {
+        let square = maze.get(s.row, s.col);
+        set_square(maze, s, square | solve::START_BIT, record);
+    }
+    let finish = solve::center_finish(maze);
+    for p in maze::ALL_DIRECTIONS {
+        let next = maze::Point {
+            row: finish.row + p.row,
+            col: finish.col + p.col,
+        };
+        let opened = solve::open_room_square(maze, next);
+        set_square(maze, next, opened, record);
+    }
+    let finish_square = maze.get(finish.row, finish.col);
+    set_square(
+        maze,
+        finish,
+        (finish_square & !maze::WALL_MASK) | solve::FINISH_BIT | maze::PATH_BIT,
+        record,
+    );
+}
+
+///
+/// Data only planning over the open squares.
+///
+// A dead end is an open square with one way out that is neither a start nor a finish. Its fill runs
+// back along the corridor until a junction. Filling the last dead branch of a junction leaves it a
+// dead end so that fill carries on through it. Loops never fill so a braided maze keeps every way
+// around them.
+fn plan_fills(maze: &maze::Maze) -> (Vec<Vec<maze::Point>>, Vec<bool>) {
+    let mut filled = vec![false; maze.as_slice().len()];
+    let mut corridors = Vec::new();
+    let mut dead_ends: VecDeque<maze::Point> = open_squares(maze)
+        .filter(|&p| !is_terminal(maze, p) && ways_out(maze, &filled, p) <= 1)
+        .collect();
+    while let Some(dead_end) = dead_ends.pop_front() {
+        if filled[index(maze, dead_end)] || ways_out(maze, &filled, dead_end) > 1 {
+            continue;
+        }
+        let mut corridor = Vec::new();
+        let mut cur = dead_end;
+        loop {
+            filled[index(maze, cur)] = true;
+            corridor.push(cur);
+            let next = match open_neighbors(maze, &filled, cur).next() {
+                Some(next) => next,
+                None => break,
+            };
+            if is_terminal(maze, next) {
+                break;
+            }
+            match ways_out(maze, &filled, next) {
+                0 | 1 => cur = next,
+                _ => break,
+            }
+        }
+        corridors.push(corridor);
+    }
+    (corridors, filled)
+}
+
+// Every square that lies on some shortest path from a start to a finish, ordered by distance from
+// the starts so the paths are drawn outward from them.
+fn shortest_squares(maze: &maze::Maze, filled: &[bool]) -> Vec<maze::Point> {
+    let starts: Vec<maze::Point> = open_squares(maze)
+        .filter(|p| solve::is_start(maze.get(p.row, p.col)))
+        .collect();
+    let finishes: Vec<maze::Point> = open_squares(maze)
+        .filter(|p| solve::is_finish(maze.get(p.row, p.col)))
+        .collect();
+    let mut on_path = vec![false; filled.len()];
+    for &s in &starts {
+        let from_start = distances(maze, filled, s);
+        for &f in &finishes {
+            let total = from_start[index(maze, f)];
+            if total == UNSEEN {
+                continue;
+            }
+            let from_finish = distances(maze, filled, f);
+            for (i, on) in on_path.iter_mut().enumerate() {
+                if from_start[i] != UNSEEN
+                    && from_finish[i] != UNSEEN
+                    && from_start[i] + from_finish[i] == total
+                {
+                    *on = true;
+                }
+            }
+        }
+    }
+    let mut squares: Vec<(u32, maze::Point)> = Vec::new();
+    for &s in &starts {
+        let from_start = distances(maze, filled, s);
+        squares.extend(
+            open_squares(maze)
+                .filter(|&p| on_path[index(maze, p)])
+                .map(|p| (from_start[index(maze, p)], p)),
+        );
+    }
+    squares.sort_by_key(|&(d, p)| (d, p.row, p.col));
+    let mut drawn = vec![false; filled.len()];
+    squares
+        .into_iter()
+        .filter(|&(_, p)| !std::mem::replace(&mut drawn[index(maze, p)], true))
+        .map(|(_, p)| p)
+        .collect()
+}
+
+fn distances(maze: &maze::Maze, filled: &[bool], source: maze::Point) -> Vec<u32> {
+    let mut dist = vec![UNSEEN; filled.len()];
+    dist[index(maze, source)] = 0;
+    let mut queue = VecDeque::from([source]);
+    while let Some(cur) = queue.pop_front() {
+        let d = dist[index(maze, cur)];
+        for next in open_neighbors(maze, filled, cur) {
+            let i = index(maze, next);
+            if dist[i] == UNSEEN {
+                dist[i] = d + 1;
+                queue.push_back(next);
+            }
+        }
+    }
+    dist
+}
+
+fn open_squares(maze: &maze::Maze) -> impl Iterator<Item = maze::Point> + '_ {
+    (1..maze.rows() - 1)
+        .flat_map(|row| (1..maze.cols() - 1).map(move |col| maze::Point { row, col }))
+        .filter(|&p| maze.is_open(p.row, p.col))
+}
+
+fn open_neighbors<'a>(
+    maze: &'a maze::Maze,
+    filled: &'a [bool],
+    p: maze::Point,
+) -> impl Iterator<Item = maze::Point> + 'a {
+    maze::CARDINAL_DIRECTIONS
+        .iter()
+        .map(move |dir| maze::Point {
+            row: p.row + dir.row,
+            col: p.col + dir.col,
+        })
+        .filter(|&next| maze.is_open(next.row, next.col) && !filled[index(maze, next)])
+}
+
+fn ways_out(maze: &maze::Maze, filled: &[bool], p: maze::Point) -> usize {
+    open_neighbors(maze, filled, p).count()
+}
+
+fn is_terminal(maze: &maze::Maze, p: maze::Point) -> bool {
+    let square = maze.get(p.row, p.col);
+    solve::is_start(square) || solve::is_finish(square)
+}
+
+fn index(maze: &maze::Maze, p: maze::Point) -> usize {
+    (p.row * maze.cols() + p.col) as usize
+}
@@ -10,9 +10,10 @@ use std::thread;
 ///
 pub fn hunt(monitor: monitor::MazeMonitor) {
     let all_start: maze::Point = if let Ok(mut lk) = monitor.lock() {
-        let all_start = solve::pick_random_point(&lk.maze);
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let all_start = solve::pick_random_point(&lk.maze, &mut rng);
         *lk.maze.get_mut(all_start.row, all_start.col) |= solve::START_BIT;
-        let finish: maze::Point = solve::pick_random_point(&lk.maze);
+        let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
         *lk.maze.get_mut(finish.row, finish.col) |= solve::FINISH_BIT;
         all_start
     } else {
@@ -51,33 +52,29 @@ pub fn hunt(monitor: monitor::MazeMonitor) {
 }
 
 pub fn corner(monitor: monitor::MazeMonitor) {
-    let mut corner_starts: [maze::Point; 4] = if let Ok(mut lk) = monitor.lock() {
-        let corner_starts = solve::set_corner_starts(&lk.maze);
+    let corner_starts: [maze::Point; 4] = if let Ok(mut lk) = monitor.lock() {
+        let mut corner_starts = solve::set_corner_starts(&lk.maze);
         for p in corner_starts {
             *lk.maze.get_mut(p.row, p.col) |= solve::START_BIT;
         }
-        let finish = maze::Point {
-            row: lk.maze.rows() / 2,
-            col: lk.maze.cols() / 2,
-        };
+        let finish = solve::center_finish(&lk.maze);
         for d in maze::ALL_DIRECTIONS {
             let next = maze::Point {
                 row: finish.row + d.row,
                 col: finish.col + d.col,
             };
-            *lk.maze.get_mut(next.row, next.col) =
-                (lk.maze.get(next.row, next.col) & !maze::WALL_MASK) | maze::PATH_BIT;
+            *lk.maze.get_mut(next.row, next.col) = solve::open_room_square(&lk.maze, next);
         }
         *lk.maze.get_mut(finish.row, finish.col) = (lk.maze.get(finish.row, finish.col)
             & !maze::WALL_MASK)
             | solve::FINISH_BIT
             | maze::PATH_BIT;
+        corner_starts.shuffle(&mut lk.maze.rng(maze::SOLVE_STREAM));
         corner_starts
     } else {
         print::maze_panic!("Thread panic.");
     };
 
-    corner_starts.shuffle(&mut thread_rng());
     let mut handles = Vec::with_capacity(solve::NUM_THREADS - 1);
     for (i_thread, &mask) in solve::THREAD_MASKS.iter().skip(1).enumerate() {
         let mc = monitor.clone();
@@ -163,10 +160,11 @@ fn hunter(monitor: monitor::MazeMonitor, guide: solve::ThreadGuide) {
 
 pub fn gather(monitor: monitor::MazeMonitor) {
     let all_start: maze::Point = if let Ok(mut lk) = monitor.lock() {
-        let all_start = solve::pick_random_point(&lk.maze);
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let all_start = solve::pick_random_point(&lk.maze, &mut rng);
         *lk.maze.get_mut(all_start.row, all_start.col) |= solve::START_BIT;
         for _ in 0..solve::NUM_GATHER_FINISHES {
-            let finish: maze::Point = solve::pick_random_point(&lk.maze);
+            let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
             *lk.maze.get_mut(finish.row, finish.col) |= solve::FINISH_BIT;
         }
         all_start
@@ -263,7 +261,8 @@ fn gatherer(monitor: monitor::MazeMonitor, guide: solve::ThreadGuide) {
 ///
 pub fn hunt_history(monitor: monitor::MazeMonitor) {
     let all_start: maze::Point = if let Ok(mut lk) = monitor.lock() {
-        let all_start = solve::pick_random_point(&lk.maze);
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let all_start = solve::pick_random_point(&lk.maze, &mut rng);
         let start_square = lk.maze.get(all_start.row, all_start.col);
         lk.maze.solve_history.push(maze::Delta {
             id: all_start,
@@ -272,7 +271,7 @@ pub fn hunt_history(monitor: monitor::MazeMonitor) {
             burst: 1,
         });
         *lk.maze.get_mut(all_start.row, all_start.col) |= solve::START_BIT;
-        let finish: maze::Point = solve::pick_random_point(&lk.maze);
+        let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
         let finish_square = lk.maze.get(finish.row, finish.col);
         lk.maze.solve_history.push(maze::Delta {
             id: finish,
@@ -318,8 +317,8 @@ pub fn hunt_history(monitor: monitor::MazeMonitor) {
 }
 
 pub fn corner_history(monitor: monitor::MazeMonitor) {
-    let mut corner_starts: [maze::Point; 4] = if let Ok(mut lk) = monitor.lock() {
-        let corner_starts = solve::set_corner_starts(&lk.maze);
+    let corner_starts: [maze::Point; 4] = if let Ok(mut lk) = monitor.lock() {
+        let mut corner_starts = solve::set_corner_starts(&lk.maze);
         for p in corner_starts {
             let start_square = lk.maze.get(p.row, p.col);
             lk.maze.solve_history.push(maze::Delta {
@@ -331,24 +330,21 @@ pub fn corner_history(monitor: monitor::MazeMonitor) {
             *lk.maze.get_mut(p.row, p.col) |= solve::START_BIT;
         }
 
-        let finish = maze::Point {
-            row: lk.maze.rows() / 2,
-            col: lk.maze.cols() / 2,
-        };
+        let finish = solve::center_finish(&lk.maze);
         for d in maze::ALL_DIRECTIONS {
             let next = maze::Point {
                 row: finish.row + d.row,
                 col: finish.col + d.col,
             };
             let next_square = lk.maze.get(next.row, next.col);
+            let opened = solve::open_room_square(&lk.maze, next);
             lk.maze.solve_history.push(maze::Delta {
                 id: next,
                 before: next_square,
-                after: (next_square & !maze::WALL_MASK) | maze::PATH_BIT,
+                after: opened,
                 burst: 1,
             });
-            *lk.maze.get_mut(next.row, next.col) =
-                (next_square & !maze::WALL_MASK) | maze::PATH_BIT;
+            *lk.maze.get_mut(next.row, next.col) = opened;
         }
         let finish_square = lk.maze.get(finish.row, finish.col);
         lk.maze.solve_history.push(maze::Delta {
@@ -359,12 +355,12 @@ pub fn corner_history(monitor: monitor::MazeMonitor) {
         });
         *lk.maze.get_mut(finish.row, finish.col) =
             (finish_square & !maze::WALL_MASK) | solve::FINISH_BIT | maze::PATH_BIT;
+        corner_starts.shuffle(&mut lk.maze.rng(maze::SOLVE_STREAM));
         corner_starts
     } else {
         print::maze_panic!("Thread panic.");
     };
 
-    corner_starts.shuffle(&mut thread_rng());
     let mut handles = Vec::with_capacity(solve::NUM_THREADS - 1);
     for (i_thread, &mask) in solve::THREAD_MASKS.iter().skip(1).enumerate() {
         let mc = monitor.clone();
@@ -468,7 +464,8 @@ fn hunter_history(monitor: monitor::MazeMonitor, guide: solve::ThreadGuide) {
 
 pub fn gather_history(monitor: monitor::MazeMonitor) {
     let all_start: maze::Point = if let Ok(mut lk) = monitor.lock() {
-        let all_start = solve::pick_random_point(&lk.maze);
+        let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+        let all_start = solve::pick_random_point(&lk.maze, &mut rng);
         let start_square = lk.maze.get(all_start.row, all_start.col);
         lk.maze.solve_history.push(maze::Delta {
             id: all_start,
@@ -478,7 +475,7 @@ pub fn gather_history(monitor: monitor::MazeMonitor) {
         });
         *lk.maze.get_mut(all_start.row, all_start.col) |= solve::START_BIT;
         for _ in 0..solve::NUM_GATHER_FINISHES {
-            let finish: maze::Point = solve::pick_random_point(&lk.maze);
+            let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
             let finish_square = lk.maze.get(finish.row, finish.col);
             lk.maze.solve_history.push(maze::Delta {
                 id: finish,
@@ -1,5 +1,11 @@
+pub mod astar;
 pub mod bfs;
+pub mod bibfs;
+pub mod dead_end;
 pub mod dfs;
 pub mod floodfs;
 pub mod rdfs;
 pub mod solve;
+pub mod stats;
+pub mod tremaux;
+pub mod wall_follower;
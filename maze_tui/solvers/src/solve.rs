@@ -41,7 +41,13 @@ pub const ONE_SEEN: ThreadCache = 0x2000000;
 pub const TWO_SEEN: ThreadCache = 0x4000000;
 pub const THREE_SEEN: ThreadCache = 0x8000000;
 pub const THREAD_CACHES: [ThreadCache; 4] = [ZERO_SEEN, ONE_SEEN, TWO_SEEN, THREE_SEEN];
-pub const SOLVER_SPEEDS: [SolveSpeedUnit; 8] = [0, 20000, 10000, 5000, 2000, 1000, 500, 250];
+// Tremaux keeps its marks in the paint alone so it leaves the cache bits to threads and painters.
+// Each color has three different channels and a green that no thread paint carries, so no mix of
+// thread paints and no painter gradient lands on one of them.
+pub const TREMAUX_PAINT: ThreadPaint = 0xFFD700;
+pub const ONE_MARK: ThreadPaint = 0xFFE066;
+pub const TWO_MARKS: ThreadPaint = 0xFF8C00;
+pub const SOLVER_SPEEDS: [SolveSpeedUnit; 8] = [0, 40000, 20000, 10000, 5000, 3000, 2000, 1000];
 
 ///
 /// Logical helpers for bitwise operations.
@@ -66,6 +72,16 @@ pub fn is_first(square: maze::Square) -> bool {
     (square & CACHE_MASK) == 0
 }
 
+// Zero when the square was never painted by the Tremaux solver, otherwise its one or two marks.
+#[inline]
+pub fn tremaux_marks(square: maze::Square) -> usize {
+    match square & THREAD_MASK {
+        ONE_MARK => 1,
+        TWO_MARKS => 2,
+        _ => 0,
+    }
+}
+
 #[inline]
 fn thread_rgb(square: maze::Square) -> RatColor {
     RatColor::Rgb(
@@ -86,7 +102,7 @@ fn is_valid_start_or_finish(maze: &maze::Maze, choice: maze::Point) -> bool {
         && choice.row < maze.rows() - 1
         && choice.col > 0
         && choice.col < maze.cols() - 1
-        && maze.path_at(choice.row, choice.col)
+        && maze.is_open(choice.row, choice.col)
         && !is_finish(maze.get(choice.row, choice.col))
         && !is_start(maze.get(choice.row, choice.col))
 }
@@ -104,35 +120,62 @@ pub fn reset_solve(maze: &mut maze::Maze) {
 
 pub fn set_corner_starts(maze: &maze::Maze) -> [maze::Point; 4] {
     let mut point1: maze::Point = maze::Point { row: 1, col: 1 };
-    if maze.wall_at(point1.row, point1.col) {
+    if !maze.is_open(point1.row, point1.col) {
         point1 = find_nearest_square(maze, point1);
     }
     let mut point2: maze::Point = maze::Point {
         row: 1,
         col: maze.cols() - 2,
     };
-    if maze.wall_at(point2.row, point2.col) {
+    if !maze.is_open(point2.row, point2.col) {
         point2 = find_nearest_square(maze, point2);
     }
     let mut point3: maze::Point = maze::Point {
         row: maze.rows() - 2,
         col: 1,
     };
-    if maze.wall_at(point3.row, point3.col) {
+    if !maze.is_open(point3.row, point3.col) {
         point3 = find_nearest_square(maze, point3);
     }
     let mut point4: maze::Point = maze::Point {
         row: maze.rows() - 2,
         col: maze.cols() - 2,
     };
-    if maze.wall_at(point4.row, point4.col) {
+    if !maze.is_open(point4.row, point4.col) {
         point4 = find_nearest_square(maze, point4);
     }
     [point1, point2, point3, point4]
 }
 
-pub fn pick_random_point(maze: &maze::Maze) -> maze::Point {
-    let mut gen = thread_rng();
+// Corner solvers race to a room opened around the center. A shaped maze moves the room to the closest
+// square inside its outline.
+pub fn center_finish(maze: &maze::Maze) -> maze::Point {
+    let center = maze::Point {
+        row: maze.rows() / 2,
+        col: maze.cols() / 2,
+    };
+    if maze.in_mask(center.row, center.col) {
+        return center;
+    }
+    match maze.find_nearest(center, 1, |p| maze.in_mask(p.row, p.col)) {
+        Some(p) => p,
+        None => {
+            print::maze_panic!("Could not place a finish in this maze. Was it built correctly?")
+        }
+    }
+}
+
+// The room around the finish stops at the outline of a shaped maze.
+pub fn open_room_square(maze: &maze::Maze, p: maze::Point) -> maze::Square {
+    let square = maze.get(p.row, p.col);
+    if maze.in_mask(p.row, p.col) {
+        (square & !maze::WALL_MASK) | maze::PATH_BIT
+    } else {
+        square
+    }
+}
+
+pub fn pick_random_point(maze: &maze::Maze, gen: &mut StdRng) -> maze::Point {
     let choice = maze::Point {
         row: gen.gen_range(1..maze.rows() - 2),
         col: gen.gen_range(1..maze.cols() - 2),
@@ -153,11 +196,18 @@ pub fn find_nearest_square(maze: &maze::Maze, choice: maze::Point) -> maze::Poin
             return next;
         }
     }
-    for r in 1..maze.rows() - 1 {
-        for c in 1..maze.cols() - 1 {
-            let cur = maze::Point { row: r, col: c };
-            if is_valid_start_or_finish(maze, cur) {
-                return cur;
+    // A corner of a shaped maze may be far from the shape so search outward to stay near it.
+    if maze.has_mask() {
+        if let Some(p) = maze.find_nearest(choice, 1, |p| is_valid_start_or_finish(maze, p)) {
+            return p;
+        }
+    } else {
+        for r in 1..maze.rows() - 1 {
+            for c in 1..maze.cols() - 1 {
+                let cur = maze::Point { row: r, col: c };
+                if is_valid_start_or_finish(maze, cur) {
+                    return cur;
+                }
             }
         }
     }
@@ -193,6 +243,19 @@ pub fn decode_square(wall_row: &[char], square: maze::Square) -> Cell {
             modifier: Modifier::BOLD,
             skip: false,
         }
+    } else if tremaux_marks(square) != 0 {
+        Cell {
+            symbol: match tremaux_marks(square) {
+                1 => "•",
+                _ => "×",
+            }
+            .to_string(),
+            fg: thread_rgb(square),
+            bg: RatColor::Reset,
+            underline_color: RatColor::Reset,
+            modifier: Modifier::BOLD,
+            skip: false,
+        }
     } else if is_color(square) {
         Cell {
             symbol: "█".to_string(),
@@ -0,0 +1,207 @@
+use crate::solve;
+use maze;
+
+use std::collections::VecDeque;
+use std::fmt;
+
+// Measurements of a finished maze for comparing builders by more than eye. Lengths count the moves
+// between open squares so a corridor joining two neighboring cells is two moves long.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub cells: usize,
+    pub dead_ends: usize,
+    pub three_way: usize,
+    pub four_way: usize,
+    pub longest_corridor: usize,
+    pub solution: Option<usize>,
+    pub visited: usize,
+    pub diameter: usize,
+    pub river: f64,
+}
+
+const UNSEEN: u32 = u32::MAX;
+
+///
+/// Measurements are taken over the open squares so modifications that carve between cells count.
+///
+// The solution runs from any start to the nearest finish so it is only known after a solver has
+// placed them. Visited counts the open squares a solver colored so two searches on one seed can be
+// compared. The diameter takes the farthest square from the farthest square of the first cell.
+// That is exact for a perfect maze and never longer than the true diameter once there are loops.
+// The river factor is the average moves from a dead end back to a junction. A maze with more river
+// flows into fewer but longer dead ends.
+pub fn measure(maze: &maze::Blueprint) -> Stats {
+    let mut stats = Stats::default();
+    let mut dead_end_moves = 0;
+    let mut first: Option<maze::Point> = None;
+    for row in 1..maze.rows - 1 {
+        for col in 1..maze.cols - 1 {
+            let p = maze::Point { row, col };
+            if !is_open(maze, p) {
+                continue;
+            }
+            if solve::is_color(maze.get(row, col)) {
+                stats.visited += 1;
+            }
+            if row % 2 == 1 && col % 2 == 1 {
+                stats.cells += 1;
+                first.get_or_insert(p);
+            }
+            let ways = degree(maze, p);
+            match ways {
+                1 => stats.dead_ends += 1,
+                3 => stats.three_way += 1,
+                4 => stats.four_way += 1,
+                _ => continue,
+            }
+            for next in open_neighbors(maze, p) {
+                let moves = corridor_moves(maze, p, next);
+                stats.longest_corridor = stats.longest_corridor.max(moves);
+                if ways == 1 {
+                    dead_end_moves += moves;
+                }
+            }
+        }
+    }
+    if stats.dead_ends != 0 {
+        stats.river = dead_end_moves as f64 / stats.dead_ends as f64;
+    }
+    let starts: Vec<maze::Point> = open_squares(maze)
+        .filter(|p| solve::is_start(maze.get(p.row, p.col)))
+        .collect();
+    if !starts.is_empty() {
+        let dist = distances(maze, &starts);
+        stats.solution = open_squares(maze)
+            .filter(|p| solve::is_finish(maze.get(p.row, p.col)))
+            .map(|p| dist[(p.row * maze.cols + p.col) as usize])
+            .filter(|&d| d != UNSEEN)
+            .min()
+            .map(|d| d as usize);
+    }
+    if let Some(first) = first {
+        let far = farthest(maze, &distances(maze, &[first])).0;
+        stats.diameter = farthest(maze, &distances(maze, &[far])).1;
+    }
+    stats
+}
+
+///
+/// Data only helpers.
+///
+// The perimeter is checked first so the neighbors of an edge square are never read.
+fn is_open(maze: &maze::Blueprint, p: maze::Point) -> bool {
+    p.row > 0
+        && p.row < maze.rows - 1
+        && p.col > 0
+        && p.col < maze.cols - 1
+        && maze.is_open(p.row, p.col)
+}
+
+fn open_neighbors(
+    maze: &maze::Blueprint,
+    p: maze::Point,
+) -> impl Iterator<Item = maze::Point> + '_ {
+    maze::CARDINAL_DIRECTIONS
+        .iter()
+        .map(move |dir| maze::Point {
+            row: p.row + dir.row,
+            col: p.col + dir.col,
+        })
+        .filter(|&next| is_open(maze, next))
+}
+
+fn open_squares(maze: &maze::Blueprint) -> impl Iterator<Item = maze::Point> + '_ {
+    (1..maze.rows - 1)
+        .flat_map(|row| (1..maze.cols - 1).map(move |col| maze::Point { row, col }))
+        .filter(|&p| is_open(maze, p))
+}
+
+fn degree(maze: &maze::Blueprint, p: maze::Point) -> usize {
+    open_neighbors(maze, p).count()
+}
+
+// Follows squares with exactly two ways out until reaching a dead end or junction.
+fn corridor_moves(maze: &maze::Blueprint, from: maze::Point, first: maze::Point) -> usize {
+    let mut prev = from;
+    let mut cur = first;
+    let mut moves = 1;
+    while degree(maze, cur) == 2 {
+        match open_neighbors(maze, cur).find(|&next| next != prev) {
+            Some(next) => {
+                prev = cur;
+                cur = next;
+                moves += 1;
+            }
+            None => break,
+        }
+        if cur == from {
+            break;
+        }
+    }
+    moves
+}
+
+fn distances(maze: &maze::Blueprint, sources: &[maze::Point]) -> Vec<u32> {
+    let mut dist = vec![UNSEEN; maze.buf.len()];
+    let mut queue = VecDeque::new();
+    for &s in sources {
+        dist[(s.row * maze.cols + s.col) as usize] = 0;
+        queue.push_back(s);
+    }
+    while let Some(cur) = queue.pop_front() {
+        let d = dist[(cur.row * maze.cols + cur.col) as usize];
+        for next in open_neighbors(maze, cur) {
+            let i = (next.row * maze.cols + next.col) as usize;
+            if dist[i] == UNSEEN {
+                dist[i] = d + 1;
+                queue.push_back(next);
+            }
+        }
+    }
+    dist
+}
+
+fn farthest(maze: &maze::Blueprint, dist: &[u32]) -> (maze::Point, usize) {
+    let (i, &d) = dist
+        .iter()
+        .enumerate()
+        .filter(|(_, &d)| d != UNSEEN)
+        .max_by_key(|(_, &d)| d)
+        .unwrap_or((0, &0));
+    (
+        maze::Point {
+            row: i as i32 / maze.cols,
+            col: i as i32 % maze.cols,
+        },
+        d as usize,
+    )
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let percent = if self.cells == 0 {
+            0.0
+        } else {
+            100.0 * self.dead_ends as f64 / self.cells as f64
+        };
+        writeln!(f, "open cells: {}", self.cells)?;
+        writeln!(
+            f,
+            "dead ends: {} ({:.1}% of cells)",
+            self.dead_ends, percent
+        )?;
+        writeln!(
+            f,
+            "junctions: {} three-way, {} four-way",
+            self.three_way, self.four_way
+        )?;
+        writeln!(f, "longest corridor: {} moves", self.longest_corridor)?;
+        match self.solution {
+            Some(moves) => writeln!(f, "solution: {} moves", moves)?,
+            None => writeln!(f, "solution: none")?,
+        }
+        writeln!(f, "visited: {} squares", self.visited)?;
+        writeln!(f, "diameter: {} moves", self.diameter)?;
+        write!(f, "river factor: {:.2}", self.river)
+    }
+}
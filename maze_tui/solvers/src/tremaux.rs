@@ -0,0 +1,204 @@
+use crate::solve;
+use maze;
+use print;
+
+use rand::prelude::*;
+use std::collections::HashMap;
+
+///
+/// Data only solvers------------------------------------------------------------------------------
+///
+pub fn tremaux(monitor: monitor::MazeMonitor) {
+    explore(monitor, false);
+}
+
+///
+/// History based solvers for recording and playback-----------------------------------------------
+///
+pub fn tremaux_history(monitor: monitor::MazeMonitor) {
+    explore(monitor, true);
+}
+
+// Tremaux walks from junction to junction. A junction is any square with three or more ways out
+// along with the start, the finish, and the dead ends. The squares between two junctions are a
+// passage and every square of a passage carries the passage's marks. Each walk down a passage adds
+// one mark. At a new junction the walker takes any passage with no marks. When it reaches a
+// junction it has already visited through a passage it just marked for the first time, it turns
+// around and walks that passage back, marking it twice. Otherwise it takes a passage with the
+// fewest marks and never one with two. The walk ends at the finish or back at the start with every
+// passage marked twice. The passages left with one mark are the path from the start to the finish.
+fn explore(monitor: monitor::MazeMonitor, record: bool) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+    let start = solve::pick_random_point(&lk.maze, &mut rng);
+    let square = lk.maze.get(start.row, start.col);
+    set_square(&mut lk.maze, start, square | solve::START_BIT, record);
+    let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
+    let square = lk.maze.get(finish.row, finish.col);
+    set_square(&mut lk.maze, finish, square | solve::FINISH_BIT, record);
+
+    let mut walk = Walk {
+        maze: &mut lk.maze,
+        bare: HashMap::new(),
+        record,
+    };
+    walk.visit(start);
+    let mut cur = start;
+    // The direction back down the passage the walker arrived by and whether it was just marked once.
+    let mut arrived: Option<(maze::Point, bool)> = None;
+    let mut dirs = maze::CARDINAL_DIRECTIONS;
+    loop {
+        if solve::is_finish(walk.maze.get(cur.row, cur.col)) {
+            lk.win.get_or_insert(0);
+            return;
+        }
+        let dir = match arrived {
+            Some((back, true)) => back,
+            _ => {
+                dirs.shuffle(&mut rng);
+                match dirs
+                    .iter()
+                    .copied()
+                    .filter(|&dir| walk.is_open(step(cur, dir)))
+                    .map(|dir| (walk.marks(cur, dir), dir))
+                    .filter(|&(marks, _)| marks < 2)
+                    .min_by_key(|&(marks, _)| marks)
+                {
+                    Some((_, dir)) => dir,
+                    None => return,
+                }
+            }
+        };
+        let (next, back, marks) = walk.passage(cur, dir);
+        let seen = walk.visited(next);
+        walk.visit(next);
+        arrived = Some((back, seen && marks == 1));
+        cur = next;
+    }
+}
+
+///
+/// Square helpers shared by both kinds of solver.
+///
+struct Walk<'a> {
+    maze: &'a mut maze::Maze,
+    // Two junctions side by side share a passage with no squares to draw its marks on.
+    bare: HashMap<(maze::Point, maze::Point), usize>,
+    record: bool,
+}
+
+impl Walk<'_> {
+    fn is_open(&self, p: maze::Point) -> bool {
+        self.maze.is_open(p.row, p.col)
+    }
+
+    fn is_junction(&self, p: maze::Point) -> bool {
+        let square = self.maze.get(p.row, p.col);
+        solve::is_start(square)
+            || solve::is_finish(square)
+            || maze::CARDINAL_DIRECTIONS
+                .iter()
+                .filter(|&&dir| self.is_open(step(p, dir)))
+                .count()
+                != 2
+    }
+
+    fn visited(&self, p: maze::Point) -> bool {
+        (self.maze.get(p.row, p.col) & solve::THREAD_MASK) == solve::TREMAUX_PAINT
+    }
+
+    // A visited junction wears the junction paint rather than a mark because its marks belong to its
+    // passages.
+    fn visit(&mut self, p: maze::Point) {
+        let square = self.maze.get(p.row, p.col);
+        let after = (square & !solve::THREAD_MASK) | solve::TREMAUX_PAINT;
+        set_square(self.maze, p, after, self.record);
+    }
+
+    // The marks on the passage leaving junction p in direction dir.
+    fn marks(&self, p: maze::Point, dir: maze::Point) -> usize {
+        let next = step(p, dir);
+        if self.is_junction(next) {
+            return self.bare.get(&bare_key(p, next)).copied().unwrap_or(0);
+        }
+        solve::tremaux_marks(self.maze.get(next.row, next.col))
+    }
+
+    // Walks the passage leaving junction p in direction dir, marking it once more on the way. Returns
+    // the junction at the other end, the direction back into the passage, and the new mark count.
+    fn passage(&mut self, p: maze::Point, dir: maze::Point) -> (maze::Point, maze::Point, usize) {
+        let marks = self.marks(p, dir) + 1;
+        let mut prev = p;
+        let mut cur = step(p, dir);
+        if self.is_junction(cur) {
+            self.bare.insert(bare_key(prev, cur), marks);
+            return (cur, back(cur, prev), marks);
+        }
+        let paint = match marks {
+            1 => solve::ONE_MARK,
+            _ => solve::TWO_MARKS,
+        };
+        while !self.is_junction(cur) {
+            mark(self.maze, cur, paint, self.record);
+            let next = match maze::CARDINAL_DIRECTIONS
+                .iter()
+                .map(|&dir| step(cur, dir))
+                .find(|&next| next != prev && self.is_open(next))
+            {
+                Some(n) => n,
+                None => print::maze_panic!("Tremaux passage square lost its way forward."),
+            };
+            prev = cur;
+            cur = next;
+        }
+        (cur, back(cur, prev), marks)
+    }
+}
+
+#[inline]
+fn step(p: maze::Point, dir: maze::Point) -> maze::Point {
+    maze::Point {
+        row: p.row + dir.row,
+        col: p.col + dir.col,
+    }
+}
+
+#[inline]
+fn back(from: maze::Point, to: maze::Point) -> maze::Point {
+    maze::Point {
+        row: to.row - from.row,
+        col: to.col - from.col,
+    }
+}
+
+#[inline]
+fn bare_key(a: maze::Point, b: maze::Point) -> (maze::Point, maze::Point) {
+    if (a.row, a.col) < (b.row, b.col) {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+// A mark replaces whatever paint the square had but keeps the start and finish bits.
+fn mark(maze: &mut maze::Maze, p: maze::Point, marks: solve::ThreadPaint, record: bool) {
+    let square = maze.get(p.row, p.col);
+    let after = (square & !solve::THREAD_MASK) | marks;
+    set_square(maze, p, after, record);
+}
+
+fn set_square(maze: &mut maze::Maze, p: maze::Point, after: maze::Square, record: bool) {
+    if record {
+        let before = maze.get(p.row, p.col);
+        maze.solve_history.push(maze::Delta {
+            id: p,
+            before,
+            after,
+            burst: 1,
+        });
+    }
+    *maze.get_mut(p.row, p.col) = after;
+}
@@ -0,0 +1,282 @@
+use crate::solve;
+use maze;
+use print;
+
+use std::collections::{HashMap, HashSet};
+
+// A follower that keeps coming back to the same square facing the same way will never reach the
+// finish. That happens when a modification cuts loops and the start wall never touches the finish.
+// The loop it was stuck in is redrawn in this color so the reason it gave up is plain to see.
+pub const GAVE_UP_PAINT: solve::ThreadPaint = 0xFF0000;
+
+const LEFT_PAINT: solve::ThreadPaint = solve::THREAD_MASKS[0];
+const RIGHT_PAINT: solve::ThreadPaint = solve::THREAD_MASKS[1];
+const PLEDGE_FOLLOW_PAINT: solve::ThreadPaint = solve::THREAD_MASKS[2];
+const PLEDGE_STRAIGHT_PAINT: solve::ThreadPaint = solve::THREAD_MASKS[3];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Hand {
+    Left,
+    Right,
+}
+
+// Pledge keeps a tally of its turns and only lets go of the wall when the tally returns to zero.
+// The tally can drift forever around a closed room so the walk is also capped in length.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Walker {
+    pos: maze::Point,
+    dir: usize,
+    following: bool,
+    turns: i32,
+}
+
+enum Ending {
+    Finished,
+    GaveUp(Vec<maze::Point>),
+}
+
+///
+/// Data only solvers------------------------------------------------------------------------------
+///
+pub fn left_hand(monitor: monitor::MazeMonitor) {
+    follow(monitor, Hand::Left, false);
+}
+
+pub fn right_hand(monitor: monitor::MazeMonitor) {
+    follow(monitor, Hand::Right, false);
+}
+
+pub fn pledge(monitor: monitor::MazeMonitor) {
+    follow(monitor, Hand::Left, true);
+}
+
+fn follow(monitor: monitor::MazeMonitor, hand: Hand, pledge: bool) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+    let start = solve::pick_random_point(&lk.maze, &mut rng);
+    *lk.maze.get_mut(start.row, start.col) |= solve::START_BIT;
+    let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
+    *lk.maze.get_mut(finish.row, finish.col) |= solve::FINISH_BIT;
+
+    let (trail, ending) = walk(&lk.maze, hand, pledge, start, finish);
+    for w in trail {
+        *lk.maze.get_mut(w.pos.row, w.pos.col) |= walker_paint(hand, pledge, &w);
+    }
+    match ending {
+        Ending::Finished => {
+            lk.win.get_or_insert(0);
+        }
+        Ending::GaveUp(stuck) => {
+            for p in stuck {
+                let square = lk.maze.get(p.row, p.col);
+                *lk.maze.get_mut(p.row, p.col) = (square & !solve::THREAD_MASK) | GAVE_UP_PAINT;
+            }
+        }
+    }
+}
+
+///
+/// History based solvers for recording and playback-----------------------------------------------
+///
+pub fn left_hand_history(monitor: monitor::MazeMonitor) {
+    follow_history(monitor, Hand::Left, false);
+}
+
+pub fn right_hand_history(monitor: monitor::MazeMonitor) {
+    follow_history(monitor, Hand::Right, false);
+}
+
+pub fn pledge_history(monitor: monitor::MazeMonitor) {
+    follow_history(monitor, Hand::Left, true);
+}
+
+fn follow_history(monitor: monitor::MazeMonitor, hand: Hand, pledge: bool) {
+    let mut lk = match monitor.lock() {
+        Ok(l) => l,
+        Err(_) => print::maze_panic!("uncontested lock failure"),
+    };
+    let mut rng = lk.maze.rng(maze::SOLVE_STREAM);
+    let start = solve::pick_random_point(&lk.maze, &mut rng);
+    let start_square = lk.maze.get(start.row, start.col);
+    lk.maze.solve_history.push(maze::Delta {
+        id: start,
+        before: start_square,
+        after: start_square | solve::START_BIT,
+        burst: 1,
+    });
+    *lk.maze.get_mut(start.row, start.col) |= solve::START_BIT;
+    let finish: maze::Point = solve::pick_random_point(&lk.maze, &mut rng);
+    let finish_square = lk.maze.get(finish.row, finish.col);
+    lk.maze.solve_history.push(maze::Delta {
+        id: finish,
+        before: finish_square,
+        after: finish_square | solve::FINISH_BIT,
+        burst: 1,
+    });
+    *lk.maze.get_mut(finish.row, finish.col) |= solve::FINISH_BIT;
+
+    // The walk only reads the maze so every square it paints is recorded once it is over.
+    let (trail, ending) = walk(&lk.maze, hand, pledge, start, finish);
+    for w in trail {
+        let (p, paint) = (w.pos, walker_paint(hand, pledge, &w));
+        let before = lk.maze.get(p.row, p.col);
+        if before & paint == paint {
+            continue;
+        }
+        lk.maze.solve_history.push(maze::Delta {
+            id: p,
+            before,
+            after: before | paint,
+            burst: 1,
+        });
+        *lk.maze.get_mut(p.row, p.col) |= paint;
+    }
+    match ending {
+        Ending::Finished => {
+            lk.win.get_or_insert(0);
+        }
+        Ending::GaveUp(stuck) => {
+            for p in stuck {
+                let before = lk.maze.get(p.row, p.col);
+                let after = (before & !solve::THREAD_MASK) | GAVE_UP_PAINT;
+                if before == after {
+                    continue;
+                }
+                lk.maze.solve_history.push(maze::Delta {
+                    id: p,
+                    before,
+                    after,
+                    burst: 1,
+                });
+                *lk.maze.get_mut(p.row, p.col) = after;
+            }
+        }
+    }
+}
+
+///
+/// Walking logic shared by both kinds of solver.
+///
+// The walker first heads toward the finish along the longer axis. A plain follower takes hold of
+// the first wall it meets and never lets go. Pledge lets go whenever its turns cancel out, which is
+// when it faces its first heading again.
+fn walk(
+    maze: &maze::Maze,
+    hand: Hand,
+    pledge: bool,
+    start: maze::Point,
+    finish: maze::Point,
+) -> (Vec<Walker>, Ending) {
+    let mut w = Walker {
+        pos: start,
+        dir: heading_toward(start, finish),
+        following: false,
+        turns: 0,
+    };
+    let open_squares = (0..maze.rows())
+        .flat_map(|r| (0..maze.cols()).map(move |c| (r, c)))
+        .filter(|&(r, c)| maze.is_open(r, c))
+        .count();
+    let max_steps = open_squares * solve::NUM_DIRECTIONS * solve::NUM_DIRECTIONS;
+    let mut seen: HashSet<Walker> = HashSet::new();
+    let mut last_seen: HashMap<(maze::Point, usize), usize> = HashMap::new();
+    let mut trail: Vec<Walker> = Vec::with_capacity(solve::INITIAL_PATH_LEN);
+    loop {
+        trail.push(w);
+        if solve::is_finish(maze.get(w.pos.row, w.pos.col)) {
+            return (trail, Ending::Finished);
+        }
+        // A plain follower repeats itself exactly once it is lost so its tally is not part of the
+        // state. Pledge needs the tally because the same square and heading may be passed again
+        // with a different count.
+        let state = Walker {
+            turns: if pledge { w.turns } else { 0 },
+            ..w
+        };
+        if !seen.insert(state) || trail.len() > max_steps {
+            let from = last_seen.get(&(w.pos, w.dir)).copied().unwrap_or(0);
+            let stuck = trail[from..].iter().map(|w| w.pos).collect();
+            return (trail, Ending::GaveUp(stuck));
+        }
+        last_seen.insert((w.pos, w.dir), trail.len() - 1);
+        step(maze, hand, pledge, &mut w);
+    }
+}
+
+// Moves the walker at most one square. Turning in place without moving still counts as a step so
+// a walker boxed in on three sides turns around over a few steps.
+fn step(maze: &maze::Maze, hand: Hand, pledge: bool, w: &mut Walker) {
+    if !w.following {
+        let next = ahead(w.pos, w.dir);
+        if maze.is_open(next.row, next.col) {
+            w.pos = next;
+            return;
+        }
+        w.following = true;
+        turn_away(hand, w);
+        return;
+    }
+    let toward = turned(hand, w.dir);
+    let side = ahead(w.pos, toward);
+    let next = ahead(w.pos, w.dir);
+    if maze.is_open(side.row, side.col) {
+        w.dir = toward;
+        w.turns += 1;
+        w.pos = side;
+    } else if maze.is_open(next.row, next.col) {
+        w.pos = next;
+    } else {
+        turn_away(hand, w);
+    }
+    if pledge && w.turns == 0 {
+        w.following = false;
+    }
+}
+
+fn turn_away(hand: Hand, w: &mut Walker) {
+    let away = match hand {
+        Hand::Left => Hand::Right,
+        Hand::Right => Hand::Left,
+    };
+    w.dir = turned(away, w.dir);
+    w.turns -= 1;
+}
+
+// The cardinal directions run clockwise from north so a right turn is the next one.
+fn turned(hand: Hand, dir: usize) -> usize {
+    match hand {
+        Hand::Left => (dir + solve::NUM_DIRECTIONS - 1) % solve::NUM_DIRECTIONS,
+        Hand::Right => (dir + 1) % solve::NUM_DIRECTIONS,
+    }
+}
+
+fn ahead(p: maze::Point, dir: usize) -> maze::Point {
+    maze::Point {
+        row: p.row + maze::CARDINAL_DIRECTIONS[dir].row,
+        col: p.col + maze::CARDINAL_DIRECTIONS[dir].col,
+    }
+}
+
+fn heading_toward(start: maze::Point, finish: maze::Point) -> usize {
+    let rows = finish.row - start.row;
+    let cols = finish.col - start.col;
+    match (rows.abs() >= cols.abs(), rows < 0, cols < 0) {
+        (true, true, _) => 0,
+        (true, false, _) => 2,
+        (false, _, false) => 1,
+        (false, _, true) => 3,
+    }
+}
+
+// Pledge shows whether it is holding the wall or walking free in two colors.
+fn walker_paint(hand: Hand, pledge: bool, w: &Walker) -> solve::ThreadPaint {
+    match (pledge, w.following, hand) {
+        (true, true, _) => PLEDGE_FOLLOW_PAINT,
+        (true, false, _) => PLEDGE_STRAIGHT_PAINT,
+        (false, _, Hand::Left) => LEFT_PAINT,
+        (false, _, Hand::Right) => RIGHT_PAINT,
+    }
+}
@@ -1,28 +1,72 @@
 use rand::seq::SliceRandom;
+use std::path::{Path, PathBuf};
 
+pub use builders::aldous_broder;
 pub use builders::arena;
+pub use builders::binary_tree;
 pub use builders::eller;
 pub use builders::grid;
+pub use builders::growing_tree;
 pub use builders::hunt_kill;
 pub use builders::kruskal;
+pub use builders::mask;
 pub use builders::modify;
 pub use builders::prim;
 pub use builders::recursive_backtracker;
 pub use builders::recursive_subdivision;
+pub use builders::sidewinder;
 pub use builders::wilson_adder;
 pub use builders::wilson_carver;
 pub use monitor;
 pub use painters::distance;
 pub use painters::rgb;
 pub use painters::runs;
+pub use solvers::astar;
 pub use solvers::bfs;
+pub use solvers::bibfs;
+pub use solvers::dead_end;
 pub use solvers::dfs;
 pub use solvers::floodfs;
 pub use solvers::rdfs;
 pub use solvers::solve;
+pub use solvers::tremaux;
+pub use solvers::wall_follower;
+
+pub use builders::build;
+
+pub const MIN_DIMENSION: i32 = 7;
+pub const MAX_DIMENSION: i32 = maze::MAX_DIMENSION;
+
+pub type BuildHistoryFunction = fn(monitor::MazeMonitor, &BuildParams);
+pub type SolveHistoryFunction = fn(monitor::MazeMonitor, &SolveParams);
+// Data only functions skip recording any history and are for front ends without playback.
+pub type BuildDataFunction = fn(monitor::MazeMonitor, &BuildParams);
+pub type SolveDataFunction = fn(monitor::MazeMonitor, &SolveParams);
+
+// The parsed parameters of whichever builder or modification accepted them. Each one only ever
+// receives its own and falls back to its defaults when it was chosen without any.
+#[derive(Clone, Copy, Default)]
+pub enum BuildParams {
+    #[default]
+    None,
+    GrowingTree(growing_tree::Policy),
+    RecursiveSubdivision(recursive_subdivision::Params),
+    Eller(eller::Params),
+    Prim(prim::Params),
+    HuntKill(hunt_kill::Params),
+    BinaryTree(binary_tree::Params),
+    Sidewinder(sidewinder::Params),
+    Braid(modify::Braid),
+    Sparse(modify::Sparse),
+}
 
-pub type BuildHistoryFunction = fn(monitor::MazeMonitor);
-pub type SolveHistoryFunction = fn(monitor::MazeMonitor);
+// Solvers follow the same pattern and most of them take no parameters at all.
+#[derive(Clone, Copy, Default)]
+pub enum SolveParams {
+    #[default]
+    None,
+    Astar(astar::Params),
+}
 
 pub struct FlagArg<'a, 'b> {
     pub flag: &'a str,
@@ -35,12 +79,32 @@ pub enum ViewingMode {
     AnimatedPlayback,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct HistoryRunner {
     pub args: maze::MazeArgs,
     pub build: BuildHistoryType,
+    // Parameters follow the builder name after a colon as in eller:h=0.3.
+    pub build_params: BuildParams,
     pub modify: Option<ModificationHistoryType>,
+    // Modifications share the builder signature so their parameters travel in the same enum.
+    pub modify_params: BuildParams,
     pub solve: SolveHistoryType,
+    // Solver parameters use the same colon syntax as in astar-hunt:heuristic=euclidean.
+    pub solve_params: SolveParams,
+    // A loaded maze replaces the builder and a save path receives the maze before it is solved.
+    pub load: Option<maze::Blueprint>,
+    pub save: Option<PathBuf>,
+    // Shapes the next new maze. A loaded maze keeps whatever mask it was saved with.
+    pub mask: Option<mask::Shape>,
+    // A replayed recording already holds both Tapes so nothing is built or solved.
+    pub replay: Option<maze::Maze>,
+    pub record: Option<PathBuf>,
+    // Indices into the speed tables. Without them playback keeps whatever speed it had.
+    pub build_speed: Option<usize>,
+    pub solve_speed: Option<usize>,
+    // Requested maze squares. Without them each front end picks a size that suits its output.
+    pub rows: Option<i32>,
+    pub cols: Option<i32>,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -55,6 +119,10 @@ pub enum BuildHistoryType {
     WilsonCarver,
     WilsonAdder,
     Grid,
+    AldousBroder,
+    BinaryTree,
+    Sidewinder,
+    GrowingTree,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -71,6 +139,18 @@ pub enum SolveHistoryType {
     FdfsHunt,
     FdfsGather,
     FdfsCorner,
+    AstarHunt,
+    AstarGather,
+    AstarCorner,
+    LeftHand,
+    RightHand,
+    Pledge,
+    FillHunt,
+    FillGather,
+    FillCorner,
+    Tremaux,
+    BibfsHunt,
+    BibfsGather,
     Distance,
     Runs,
 }
@@ -79,6 +159,8 @@ pub enum SolveHistoryType {
 pub enum ModificationHistoryType {
     Cross = 0,
     X,
+    Braid,
+    Sparse,
 }
 
 impl HistoryRunner {
@@ -89,10 +171,23 @@ impl HistoryRunner {
                 odd_cols: 111,
                 offset: maze::Offset::default(),
                 style: maze::MazeStyle::Sharp,
+                seed: 0,
             },
             build: BuildHistoryType::RecursiveBacktracker,
+            build_params: BuildParams::default(),
             modify: None,
+            modify_params: BuildParams::default(),
             solve: SolveHistoryType::DfsHunt,
+            solve_params: SolveParams::default(),
+            load: None,
+            save: None,
+            mask: None,
+            replay: None,
+            record: None,
+            build_speed: None,
+            solve_speed: None,
+            rows: None,
+            cols: None,
         }
     }
 }
@@ -117,31 +212,263 @@ pub fn match_flag(arg: &str) -> Option<&str> {
     search_table(arg, &FLAGS)
 }
 
-pub fn match_builder(arg: &str) -> Option<BuildHistoryType> {
-    search_table(arg, &HISTORY_BUILDERS)
+// Parameters follow the builder name after a colon. Builders without parameters reject them.
+pub fn match_builder(arg: &str) -> Result<(BuildHistoryType, BuildParams), String> {
+    let (name, params) = match arg.split_once(':') {
+        Some((name, params)) => (name, Some(params)),
+        None => (arg, None),
+    };
+    let builder =
+        search_table(name, &HISTORY_BUILDERS).ok_or(format!("builder[{}] does not exist", name))?;
+    let params = match params {
+        Some(p) => p,
+        None => return Ok((builder, BuildParams::None)),
+    };
+    let build_params = match builder {
+        BuildHistoryType::GrowingTree => BuildParams::GrowingTree(params.parse()?),
+        BuildHistoryType::RecursiveSubdivision => {
+            BuildParams::RecursiveSubdivision(params.parse()?)
+        }
+        BuildHistoryType::Eller => BuildParams::Eller(params.parse()?),
+        BuildHistoryType::Prim => BuildParams::Prim(params.parse()?),
+        BuildHistoryType::HuntKill => BuildParams::HuntKill(params.parse()?),
+        BuildHistoryType::BinaryTree => BuildParams::BinaryTree(params.parse()?),
+        BuildHistoryType::Sidewinder => BuildParams::Sidewinder(params.parse()?),
+        _ => return Err(format!("builder[{}] takes no parameters", name)),
+    };
+    Ok((builder, build_params))
+}
+
+// Modification parameters use the same colon syntax as the builders.
+pub fn match_modifier(arg: &str) -> Result<(ModificationHistoryType, BuildParams), String> {
+    let (name, params) = match arg.split_once(':') {
+        Some((name, params)) => (name, Some(params)),
+        None => (arg, None),
+    };
+    let modifier = search_table(name, &HISTORY_MODIFICATIONS)
+        .ok_or(format!("modification[{}] does not exist", name))?;
+    let params = match params {
+        Some(p) => p,
+        None => return Ok((modifier, BuildParams::None)),
+    };
+    let modify_params = match modifier {
+        ModificationHistoryType::Braid => BuildParams::Braid(params.parse()?),
+        ModificationHistoryType::Sparse => BuildParams::Sparse(params.parse()?),
+        _ => return Err(format!("modification[{}] takes no parameters", name)),
+    };
+    Ok((modifier, modify_params))
 }
 
-pub fn match_modifier(arg: &str) -> Option<ModificationHistoryType> {
-    search_table(arg, &HISTORY_MODIFICATIONS)
+// Built in shapes go by name and text follows a colon as in text:hello. Anything else is a file.
+pub fn match_mask(arg: &str) -> Result<mask::Shape, String> {
+    match arg.split_once(':') {
+        Some(("text", words)) => mask::Shape::text(words),
+        _ => match arg {
+            "circle" => Ok(mask::Shape::Circle),
+            "heart" => Ok(mask::Shape::Heart),
+            path => mask::Shape::load(Path::new(path))
+                .map_err(|e| format!("could not load mask[{}]: {}", path, e)),
+        },
+    }
 }
 
-pub fn match_solver(arg: &str) -> Option<SolveHistoryType> {
-    search_table(arg, &HISTORY_SOLVERS)
+// Solver parameters use the same colon syntax as the builders.
+pub fn match_solver(arg: &str) -> Result<(SolveHistoryType, SolveParams), String> {
+    let (name, params) = match arg.split_once(':') {
+        Some((name, params)) => (name, Some(params)),
+        None => (arg, None),
+    };
+    let solver =
+        search_table(name, &HISTORY_SOLVERS).ok_or(format!("solver[{}] does not exist", name))?;
+    let mut solve_params = SolveParams::default();
+    let params = match params {
+        Some(p) => p,
+        None => return Ok((solver, solve_params)),
+    };
+    match solver {
+        SolveHistoryType::AstarHunt
+        | SolveHistoryType::AstarGather
+        | SolveHistoryType::AstarCorner => solve_params = SolveParams::Astar(params.parse()?),
+        _ => return Err(format!("solver[{}] takes no parameters", name)),
+    }
+    Ok((solver, solve_params))
 }
 
 pub fn match_walls(arg: &str) -> Option<maze::MazeStyle> {
     search_table(arg, &WALL_STYLES)
 }
 
+pub fn match_speed(arg: &str) -> Option<usize> {
+    search_table(arg, &ANIMATION_SPEEDS)
+}
+
+// Every front end shares these flags so a command means the same thing wherever it is typed.
+pub fn set_arg(run: &mut HistoryRunner, args: &FlagArg) -> Result<(), String> {
+    match args.flag {
+        "-b" => set_builder(run, args),
+        "-m" => match_modifier(args.arg)
+            .map(|(modifier, modify_params)| {
+                run.modify = Some(modifier);
+                run.modify_params = modify_params;
+            })
+            .map_err(|e| format!("{}. {}", err_string(args), e)),
+        "-s" => match_solver(args.arg)
+            .map(|(solver, solve_params)| {
+                run.solve = solver;
+                run.solve_params = solve_params;
+            })
+            .map_err(|e| format!("{}. {}", err_string(args), e)),
+        "-w" => match_walls(args.arg)
+            .map(|wall_style| run.args.style = wall_style)
+            .ok_or(err_string(args)),
+        "-seed" => args
+            .arg
+            .parse::<maze::Seed>()
+            .map(|seed| run.args.seed = seed)
+            .map_err(|_| err_string(args)),
+        "-save" => {
+            run.save = Some(PathBuf::from(args.arg));
+            Ok(())
+        }
+        "-load" => maze::Blueprint::load(Path::new(args.arg))
+            .map(|blueprint| run.load = Some(blueprint))
+            .map_err(|e| format!("could not load maze[{}]: {}", args.arg, e)),
+        "-mask" => match_mask(args.arg).map(|shape| run.mask = Some(shape)),
+        "-ba" => match_speed(args.arg)
+            .map(|speed| run.build_speed = Some(speed))
+            .ok_or(err_string(args)),
+        "-sa" => match_speed(args.arg)
+            .map(|speed| run.solve_speed = Some(speed))
+            .ok_or(err_string(args)),
+        "-record" => {
+            run.record = Some(PathBuf::from(args.arg));
+            Ok(())
+        }
+        "-replay" => maze::Maze::load_recording(Path::new(args.arg))
+            .map(|recording| run.replay = Some(recording))
+            .map_err(|e| format!("could not replay run[{}]: {}", args.arg, e)),
+        "-r" => parse_dimension(args).map(|rows| run.rows = Some(rows)),
+        "-c" => parse_dimension(args).map(|cols| run.cols = Some(cols)),
+        _ => Err(err_string(args)),
+    }
+}
+
+fn set_builder(run: &mut HistoryRunner, args: &FlagArg) -> Result<(), String> {
+    let (builder, build_params) =
+        match_builder(args.arg).map_err(|e| format!("{}. {}", err_string(args), e))?;
+    run.build = builder;
+    run.build_params = build_params;
+    Ok(())
+}
+
+fn parse_dimension(args: &FlagArg) -> Result<i32, String> {
+    match args.arg.parse::<i32>() {
+        Ok(d) if (MIN_DIMENSION..=MAX_DIMENSION).contains(&d) => Ok(d),
+        _ => Err(format!(
+            "{}. Use a number from {} to {}",
+            err_string(args),
+            MIN_DIMENSION,
+            MAX_DIMENSION
+        )),
+    }
+}
+
+fn err_string(args: &FlagArg) -> String {
+    format!("invalid flag[{}] arg[{}] combo", args.flag, args.arg)
+}
+
+impl BuildParams {
+    fn growing_tree(&self) -> growing_tree::Policy {
+        match self {
+            BuildParams::GrowingTree(policy) => *policy,
+            _ => growing_tree::Policy::default(),
+        }
+    }
+
+    fn recursive_subdivision(&self) -> recursive_subdivision::Params {
+        match self {
+            BuildParams::RecursiveSubdivision(params) => *params,
+            _ => recursive_subdivision::Params::default(),
+        }
+    }
+
+    fn eller(&self) -> eller::Params {
+        match self {
+            BuildParams::Eller(params) => *params,
+            _ => eller::Params::default(),
+        }
+    }
+
+    fn prim(&self) -> prim::Params {
+        match self {
+            BuildParams::Prim(params) => *params,
+            _ => prim::Params::default(),
+        }
+    }
+
+    fn hunt_kill(&self) -> hunt_kill::Params {
+        match self {
+            BuildParams::HuntKill(params) => *params,
+            _ => hunt_kill::Params::default(),
+        }
+    }
+
+    fn binary_tree(&self) -> binary_tree::Params {
+        match self {
+            BuildParams::BinaryTree(params) => *params,
+            _ => binary_tree::Params::default(),
+        }
+    }
+
+    fn sidewinder(&self) -> sidewinder::Params {
+        match self {
+            BuildParams::Sidewinder(params) => *params,
+            _ => sidewinder::Params::default(),
+        }
+    }
+
+    fn braid(&self) -> modify::Braid {
+        match self {
+            BuildParams::Braid(braid) => *braid,
+            _ => modify::Braid::default(),
+        }
+    }
+
+    fn sparse(&self) -> modify::Sparse {
+        match self {
+            BuildParams::Sparse(sparse) => *sparse,
+            _ => modify::Sparse::default(),
+        }
+    }
+}
+
+impl SolveParams {
+    fn astar(&self) -> astar::Params {
+        match self {
+            SolveParams::Astar(params) => *params,
+            _ => astar::Params::default(),
+        }
+    }
+}
+
 impl BuildHistoryType {
     pub fn get_fn(&self) -> BuildHistoryFunction {
         BUILD_FN_TABLE[*self as usize]
     }
 
+    pub fn get_data_fn(&self) -> BuildDataFunction {
+        BUILD_DATA_FN_TABLE[*self as usize]
+    }
+
     pub fn get_description(&self) -> &str {
         BUILD_DESCRIPTIONS_TABLE[*self as usize]
     }
 
+    // Every builder shares one speed table. The speed is the time between frames in microseconds.
+    pub fn get_speed(&self, index: usize) -> build::SpeedUnit {
+        build::BUILDER_SPEEDS[index]
+    }
+
     pub fn get_random(rng: &mut rand::rngs::ThreadRng) -> BuildHistoryType {
         *ALL_BUILDER_TYPES
             .choose(rng)
@@ -154,6 +481,18 @@ impl SolveHistoryType {
         SOLVE_FN_TABLE[*self as usize]
     }
 
+    pub fn get_data_fn(&self) -> SolveDataFunction {
+        SOLVE_DATA_FN_TABLE[*self as usize]
+    }
+
+    // Painters touch far more squares per frame than solvers so they get their own speed table.
+    pub fn get_speed(&self, index: usize) -> solve::SolveSpeedUnit {
+        match self {
+            SolveHistoryType::Distance | SolveHistoryType::Runs => rgb::ANIMATION_SPEEDS[index],
+            _ => solve::SOLVER_SPEEDS[index],
+        }
+    }
+
     pub fn get_random(rng: &mut rand::rngs::ThreadRng) -> SolveHistoryType {
         *ALL_SOLVER_TYPES
             .choose(rng)
@@ -166,6 +505,10 @@ impl ModificationHistoryType {
         MODIFICATION_FN_TABLE[*self as usize]
     }
 
+    pub fn get_data_fn(&self) -> BuildDataFunction {
+        MODIFICATION_DATA_FN_TABLE[*self as usize]
+    }
+
     pub fn get_random(rng: &mut rand::rngs::ThreadRng) -> ModificationHistoryType {
         *ALL_MODIFICATION_TYPES
             .choose(rng)
@@ -173,13 +516,31 @@ impl ModificationHistoryType {
     }
 }
 
-static FLAGS: [(&str, &str); 6] = [
+static FLAGS: [(&str, &str); 14] = [
     ("-b", "-b"),
     ("-m", "-m"),
     ("-s", "-s"),
     ("-w", "-w"),
     ("-sa", "-sa"),
     ("-ba", "-ba"),
+    ("-seed", "-seed"),
+    ("-save", "-save"),
+    ("-load", "-load"),
+    ("-mask", "-mask"),
+    ("-record", "-record"),
+    ("-replay", "-replay"),
+    ("-r", "-r"),
+    ("-c", "-c"),
+];
+
+static ANIMATION_SPEEDS: [(&str, usize); 7] = [
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
 ];
 
 static WALL_STYLES: [(&str, maze::MazeStyle); 8] = [
@@ -193,7 +554,7 @@ static WALL_STYLES: [(&str, maze::MazeStyle); 8] = [
     ("spikes", maze::MazeStyle::Spikes),
 ];
 
-static HISTORY_BUILDERS: [(&str, BuildHistoryType); 10] = [
+static HISTORY_BUILDERS: [(&str, BuildHistoryType); 14] = [
     ("arena", BuildHistoryType::Arena),
     ("rdfs", BuildHistoryType::RecursiveBacktracker),
     ("hunt-kill", BuildHistoryType::HuntKill),
@@ -204,22 +565,47 @@ static HISTORY_BUILDERS: [(&str, BuildHistoryType); 10] = [
     ("wilson", BuildHistoryType::WilsonCarver),
     ("wilson-walls", BuildHistoryType::WilsonAdder),
     ("grid", BuildHistoryType::Grid),
+    ("aldous-broder", BuildHistoryType::AldousBroder),
+    ("binary-tree", BuildHistoryType::BinaryTree),
+    ("sidewinder", BuildHistoryType::Sidewinder),
+    ("growing-tree", BuildHistoryType::GrowingTree),
+];
+
+static BUILD_FN_TABLE: [BuildHistoryFunction; 14] = [
+    |m, _| arena::generate_history(m),
+    |m, _| recursive_backtracker::generate_history(m),
+    |m, p| hunt_kill::generate_history(m, &p.hunt_kill()),
+    |m, p| recursive_subdivision::generate_history(m, &p.recursive_subdivision()),
+    |m, p| prim::generate_history(m, &p.prim()),
+    |m, _| kruskal::generate_history(m),
+    |m, p| eller::generate_history(m, &p.eller()),
+    |m, _| wilson_carver::generate_history(m),
+    |m, _| wilson_adder::generate_history(m),
+    |m, _| grid::generate_history(m),
+    |m, _| aldous_broder::generate_history(m),
+    |m, p| binary_tree::generate_history(m, &p.binary_tree()),
+    |m, p| sidewinder::generate_history(m, &p.sidewinder()),
+    |m, p| growing_tree::generate_history(m, &p.growing_tree()),
 ];
 
-static BUILD_FN_TABLE: [BuildHistoryFunction; 10] = [
-    arena::generate_history,
-    recursive_backtracker::generate_history,
-    hunt_kill::generate_history,
-    recursive_subdivision::generate_history,
-    prim::generate_history,
-    kruskal::generate_history,
-    eller::generate_history,
-    wilson_carver::generate_history,
-    wilson_adder::generate_history,
-    grid::generate_history,
+static BUILD_DATA_FN_TABLE: [BuildDataFunction; 14] = [
+    |m, _| arena::generate_maze(m),
+    |m, _| recursive_backtracker::generate_maze(m),
+    |m, p| hunt_kill::generate_maze(m, &p.hunt_kill()),
+    |m, p| recursive_subdivision::generate_maze(m, &p.recursive_subdivision()),
+    |m, p| prim::generate_maze(m, &p.prim()),
+    |m, _| kruskal::generate_maze(m),
+    |m, p| eller::generate_maze(m, &p.eller()),
+    |m, _| wilson_carver::generate_maze(m),
+    |m, _| wilson_adder::generate_maze(m),
+    |m, _| grid::generate_maze(m),
+    |m, _| aldous_broder::generate_maze(m),
+    |m, p| binary_tree::generate_maze(m, &p.binary_tree()),
+    |m, p| sidewinder::generate_maze(m, &p.sidewinder()),
+    |m, p| growing_tree::generate_maze(m, &p.growing_tree()),
 ];
 
-static ALL_BUILDER_TYPES: [BuildHistoryType; 10] = [
+static ALL_BUILDER_TYPES: [BuildHistoryType; 14] = [
     BuildHistoryType::Arena,
     BuildHistoryType::RecursiveBacktracker,
     BuildHistoryType::HuntKill,
@@ -230,9 +616,13 @@ static ALL_BUILDER_TYPES: [BuildHistoryType; 10] = [
     BuildHistoryType::WilsonCarver,
     BuildHistoryType::WilsonAdder,
     BuildHistoryType::Grid,
+    BuildHistoryType::AldousBroder,
+    BuildHistoryType::BinaryTree,
+    BuildHistoryType::Sidewinder,
+    BuildHistoryType::GrowingTree,
 ];
 
-static BUILD_DESCRIPTIONS_TABLE: [&str; 10] = [
+static BUILD_DESCRIPTIONS_TABLE: [&str; 14] = [
     include_str!("../../res/arena.txt"),
     include_str!("../../res/recursive_backtracker.txt"),
     include_str!("../../res/hunt_kill.txt"),
@@ -243,20 +633,41 @@ static BUILD_DESCRIPTIONS_TABLE: [&str; 10] = [
     include_str!("../../res/wilson_carver.txt"),
     include_str!("../../res/wilson_adder.txt"),
     include_str!("../../res/grid.txt"),
+    include_str!("../../res/aldous_broder.txt"),
+    include_str!("../../res/binary_tree.txt"),
+    include_str!("../../res/sidewinder.txt"),
+    include_str!("../../res/growing_tree.txt"),
 ];
 
-static HISTORY_MODIFICATIONS: [(&str, ModificationHistoryType); 2] = [
+static HISTORY_MODIFICATIONS: [(&str, ModificationHistoryType); 4] = [
     ("cross", ModificationHistoryType::Cross),
     ("x", ModificationHistoryType::X),
+    ("braid", ModificationHistoryType::Braid),
+    ("sparse", ModificationHistoryType::Sparse),
 ];
 
-static MODIFICATION_FN_TABLE: [BuildHistoryFunction; 2] =
-    [modify::add_cross_history, modify::add_x_history];
+static MODIFICATION_FN_TABLE: [BuildHistoryFunction; 4] = [
+    |m, _| modify::add_cross_history(m),
+    |m, _| modify::add_x_history(m),
+    |m, p| modify::add_braid_history(m, &p.braid()),
+    |m, p| modify::add_sparse_history(m, &p.sparse()),
+];
 
-static ALL_MODIFICATION_TYPES: [ModificationHistoryType; 2] =
-    [ModificationHistoryType::Cross, ModificationHistoryType::X];
+static MODIFICATION_DATA_FN_TABLE: [BuildDataFunction; 4] = [
+    |m, _| modify::add_cross(m),
+    |m, _| modify::add_x(m),
+    |m, p| modify::add_braid(m, &p.braid()),
+    |m, p| modify::add_sparse(m, &p.sparse()),
+];
 
-static HISTORY_SOLVERS: [(&str, SolveHistoryType); 14] = [
+static ALL_MODIFICATION_TYPES: [ModificationHistoryType; 4] = [
+    ModificationHistoryType::Cross,
+    ModificationHistoryType::X,
+    ModificationHistoryType::Braid,
+    ModificationHistoryType::Sparse,
+];
+
+static HISTORY_SOLVERS: [(&str, SolveHistoryType); 26] = [
     ("dfs-hunt", SolveHistoryType::DfsHunt),
     ("dfs-gather", SolveHistoryType::DfsGather),
     ("dfs-corner", SolveHistoryType::DfsCorner),
@@ -269,28 +680,81 @@ static HISTORY_SOLVERS: [(&str, SolveHistoryType); 14] = [
     ("floodfs-hunt", SolveHistoryType::FdfsHunt),
     ("floodfs-gather", SolveHistoryType::FdfsGather),
     ("floodfs-corner", SolveHistoryType::FdfsCorner),
+    ("astar-hunt", SolveHistoryType::AstarHunt),
+    ("astar-gather", SolveHistoryType::AstarGather),
+    ("astar-corner", SolveHistoryType::AstarCorner),
+    ("left-hand", SolveHistoryType::LeftHand),
+    ("right-hand", SolveHistoryType::RightHand),
+    ("pledge", SolveHistoryType::Pledge),
+    ("fill-hunt", SolveHistoryType::FillHunt),
+    ("fill-gather", SolveHistoryType::FillGather),
+    ("fill-corner", SolveHistoryType::FillCorner),
+    ("tremaux", SolveHistoryType::Tremaux),
+    ("bibfs-hunt", SolveHistoryType::BibfsHunt),
+    ("bibfs-gather", SolveHistoryType::BibfsGather),
     ("distance", SolveHistoryType::Distance),
     ("runs", SolveHistoryType::Runs),
 ];
 
-static SOLVE_FN_TABLE: [SolveHistoryFunction; 14] = [
-    dfs::hunt_history,
-    dfs::gather_history,
-    dfs::corner_history,
-    rdfs::hunt_history,
-    rdfs::gather_history,
-    rdfs::corner_history,
-    bfs::hunt_history,
-    bfs::gather_history,
-    bfs::corner_history,
-    floodfs::hunt_history,
-    floodfs::gather_history,
-    floodfs::corner_history,
-    distance::paint_distance_from_center_history,
-    runs::paint_run_lengths_history,
+static SOLVE_FN_TABLE: [SolveHistoryFunction; 26] = [
+    |m, _| dfs::hunt_history(m),
+    |m, _| dfs::gather_history(m),
+    |m, _| dfs::corner_history(m),
+    |m, _| rdfs::hunt_history(m),
+    |m, _| rdfs::gather_history(m),
+    |m, _| rdfs::corner_history(m),
+    |m, _| bfs::hunt_history(m),
+    |m, _| bfs::gather_history(m),
+    |m, _| bfs::corner_history(m),
+    |m, _| floodfs::hunt_history(m),
+    |m, _| floodfs::gather_history(m),
+    |m, _| floodfs::corner_history(m),
+    |m, p| astar::hunt_history(m, &p.astar()),
+    |m, p| astar::gather_history(m, &p.astar()),
+    |m, p| astar::corner_history(m, &p.astar()),
+    |m, _| wall_follower::left_hand_history(m),
+    |m, _| wall_follower::right_hand_history(m),
+    |m, _| wall_follower::pledge_history(m),
+    |m, _| dead_end::hunt_history(m),
+    |m, _| dead_end::gather_history(m),
+    |m, _| dead_end::corner_history(m),
+    |m, _| tremaux::tremaux_history(m),
+    |m, _| bibfs::hunt_history(m),
+    |m, _| bibfs::gather_history(m),
+    |m, _| distance::paint_distance_from_center_history(m),
+    |m, _| runs::paint_run_lengths_history(m),
+];
+
+static SOLVE_DATA_FN_TABLE: [SolveDataFunction; 26] = [
+    |m, _| dfs::hunt(m),
+    |m, _| dfs::gather(m),
+    |m, _| dfs::corner(m),
+    |m, _| rdfs::hunt(m),
+    |m, _| rdfs::gather(m),
+    |m, _| rdfs::corner(m),
+    |m, _| bfs::hunt(m),
+    |m, _| bfs::gather(m),
+    |m, _| bfs::corner(m),
+    |m, _| floodfs::hunt(m),
+    |m, _| floodfs::gather(m),
+    |m, _| floodfs::corner(m),
+    |m, p| astar::hunt(m, &p.astar()),
+    |m, p| astar::gather(m, &p.astar()),
+    |m, p| astar::corner(m, &p.astar()),
+    |m, _| wall_follower::left_hand(m),
+    |m, _| wall_follower::right_hand(m),
+    |m, _| wall_follower::pledge(m),
+    |m, _| dead_end::hunt(m),
+    |m, _| dead_end::gather(m),
+    |m, _| dead_end::corner(m),
+    |m, _| tremaux::tremaux(m),
+    |m, _| bibfs::hunt(m),
+    |m, _| bibfs::gather(m),
+    |m, _| distance::paint_distance_from_center(m),
+    |m, _| runs::paint_run_lengths(m),
 ];
 
-static ALL_SOLVER_TYPES: [SolveHistoryType; 14] = [
+static ALL_SOLVER_TYPES: [SolveHistoryType; 26] = [
     SolveHistoryType::DfsHunt,
     SolveHistoryType::DfsGather,
     SolveHistoryType::DfsCorner,
@@ -303,6 +767,18 @@ static ALL_SOLVER_TYPES: [SolveHistoryType; 14] = [
     SolveHistoryType::FdfsHunt,
     SolveHistoryType::FdfsGather,
     SolveHistoryType::FdfsCorner,
+    SolveHistoryType::AstarHunt,
+    SolveHistoryType::AstarGather,
+    SolveHistoryType::AstarCorner,
+    SolveHistoryType::LeftHand,
+    SolveHistoryType::RightHand,
+    SolveHistoryType::Pledge,
+    SolveHistoryType::FillHunt,
+    SolveHistoryType::FillGather,
+    SolveHistoryType::FillCorner,
+    SolveHistoryType::Tremaux,
+    SolveHistoryType::BibfsHunt,
+    SolveHistoryType::BibfsGather,
     SolveHistoryType::Distance,
     SolveHistoryType::Runs,
 ];